@@ -1,4 +1,6 @@
 mod error_object;
+mod parse_ast;
+mod semantic_tokens;
 mod validate;
 
 use prisma_language as psl;
@@ -0,0 +1,11 @@
+use prisma_diagnostics::Diagnostics;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+/// The AST of the schema, as a plain JavaScript object. The parser recovers
+/// from syntax errors, so this works on invalid schemas too.
+#[wasm_bindgen(js_name = "parseAst")]
+pub fn parse_ast(input_schema: &str) -> Result<JsValue, serde_wasm_bindgen::Error> {
+    let ast = prisma_parser::parse_schema(input_schema, &mut Diagnostics::new());
+
+    serde_wasm_bindgen::to_value(&ast)
+}
@@ -0,0 +1,22 @@
+use prisma_diagnostics::Encoding;
+use prisma_parser::SemanticTokenType;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The semantic tokens of the schema, in the LSP delta encoding. The columns are
+/// in UTF-16 units, like JavaScript string indexes.
+#[wasm_bindgen(js_name = "semanticTokens")]
+pub fn semantic_tokens(input_schema: &str) -> Vec<u32> {
+    let cst = prisma_parser::parse_cst(input_schema);
+    let tokens = prisma_parser::semantic_tokens(&cst);
+
+    prisma_parser::encode_semantic_tokens(&tokens, input_schema, Encoding::Utf16)
+}
+
+/// The names of the token types, in the order of their index in `semanticTokens()`.
+#[wasm_bindgen(js_name = "semanticTokenTypes")]
+pub fn semantic_token_types() -> Vec<String> {
+    SemanticTokenType::ALL
+        .iter()
+        .map(|token_type| token_type.as_str().to_owned())
+        .collect()
+}
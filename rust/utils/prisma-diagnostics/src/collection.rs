@@ -1,5 +1,6 @@
 use super::SchemaError;
-use crate::warning::SchemaWarning;
+use crate::{warning::SchemaWarning, FileId, LineIndex, SchemaFiles};
+use std::collections::HashMap;
 
 /// Represents a list of validation or parser errors and warnings.
 ///
@@ -52,6 +53,7 @@ impl Diagnostics {
         }
     }
 
+    /// Pretty print the errors of a single-file schema.
     pub fn to_pretty_string(&self, file_name: &str, schema_string: &str) -> String {
         let mut message: Vec<u8> = Vec::new();
 
@@ -63,6 +65,7 @@ impl Diagnostics {
         String::from_utf8_lossy(&message).into_owned()
     }
 
+    /// Pretty print the warnings of a single-file schema.
     pub fn warnings_to_pretty_string(&self, file_name: &str, schema_string: &str) -> String {
         let mut message: Vec<u8> = Vec::new();
 
@@ -73,6 +76,48 @@ impl Diagnostics {
 
         String::from_utf8_lossy(&message).into_owned()
     }
+
+    /// Pretty print the errors, each against the file its span is in.
+    pub fn to_pretty_string_in_files(&self, files: &SchemaFiles) -> String {
+        let mut message: Vec<u8> = Vec::new();
+        let mut indexes = HashMap::new();
+
+        for err in self.errors() {
+            let (name, source, index) = file_with_index(files, err.span().file_id, &mut indexes);
+            err.pretty_print_with_index(&mut message, name, source, index)
+                .expect("printing schema error");
+        }
+
+        String::from_utf8_lossy(&message).into_owned()
+    }
+
+    /// Pretty print the warnings, each against the file its span is in.
+    pub fn warnings_to_pretty_string_in_files(&self, files: &SchemaFiles) -> String {
+        let mut message: Vec<u8> = Vec::new();
+        let mut indexes = HashMap::new();
+
+        for warn in self.warnings() {
+            let (name, source, index) = file_with_index(files, warn.span().file_id, &mut indexes);
+            warn.pretty_print_with_index(&mut message, name, source, index)
+                .expect("printing schema warning");
+        }
+
+        String::from_utf8_lossy(&message).into_owned()
+    }
+}
+
+/// The name and source of a file, with its line index built on first use.
+fn file_with_index<'a>(
+    files: &'a SchemaFiles,
+    file_id: FileId,
+    indexes: &'a mut HashMap<FileId, LineIndex>,
+) -> (&'a str, &'a str, &'a LineIndex) {
+    let source = files.source(file_id);
+    let index = indexes
+        .entry(file_id)
+        .or_insert_with(|| LineIndex::new(source));
+
+    (files.name(file_id), source, index)
 }
 
 impl From<SchemaError> for Diagnostics {
@@ -1,8 +1,9 @@
 use colored::{ColoredString, Colorize};
 
 use crate::{
+    expected::describe_expected,
     pretty_print::{pretty_print, DiagnosticColorer},
-    Span,
+    ExpectedToken, FileId, LineIndex, Span,
 };
 use std::borrow::Cow;
 
@@ -10,12 +11,17 @@ use std::borrow::Cow;
 pub struct SchemaError {
     span: Span,
     message: Cow<'static, str>,
+    expected: Vec<ExpectedToken>,
 }
 
 impl SchemaError {
     pub(crate) fn new(message: impl Into<Cow<'static, str>>, span: Span) -> Self {
         let message = message.into();
-        SchemaError { message, span }
+        SchemaError {
+            message,
+            span,
+            expected: Vec::new(),
+        }
     }
 
     pub fn new_static(message: &'static str, span: Span) -> Self {
@@ -143,13 +149,38 @@ impl SchemaError {
         Self::new(format!("Error validating: {message}"), span)
     }
 
-    pub fn new_parser_error(expected_str: String, span: Span) -> SchemaError {
+    pub fn new_model_validation_error(
+        message: &str,
+        block_type: &'static str,
+        model_name: &str,
+        span: Span,
+    ) -> SchemaError {
         Self::new(
-            format!("Unexpected token. Expected one of: {expected_str}"),
+            format!("Error validating {block_type} \"{model_name}\": {message}"),
             span,
         )
     }
 
+    pub fn new_legacy_parser_error(
+        message: impl Into<Cow<'static, str>>,
+        span: Span,
+    ) -> SchemaError {
+        Self::new(message.into(), span)
+    }
+
+    /// The message describes the `expected` tokens, in order.
+    pub fn new_parser_error(expected: Vec<ExpectedToken>, span: Span) -> SchemaError {
+        let message = format!(
+            "Unexpected token. Expected {}.",
+            describe_expected(&expected)
+        );
+
+        SchemaError {
+            expected,
+            ..Self::new(message, span)
+        }
+    }
+
     pub fn new_functional_evaluation_error(
         message: impl Into<Cow<'static, str>>,
         span: Span,
@@ -234,16 +265,48 @@ impl SchemaError {
         &self.message
     }
 
+    /// The same error, moved by `delta` bytes.
+    pub fn shifted(self, delta: isize) -> SchemaError {
+        SchemaError {
+            span: self.span.shifted(delta),
+            ..self
+        }
+    }
+
+    /// The same error, in the given file.
+    pub fn in_file(self, file_id: FileId) -> SchemaError {
+        SchemaError {
+            span: self.span.in_file(file_id),
+            ..self
+        }
+    }
+
+    /// What the parser expected at this location. Only parser errors have it.
+    pub fn expected(&self) -> &[ExpectedToken] {
+        &self.expected
+    }
+
     pub fn pretty_print(
         &self,
         f: &mut dyn std::io::Write,
         file_name: &str,
         text: &str,
+    ) -> std::io::Result<()> {
+        self.pretty_print_with_index(f, file_name, text, &LineIndex::new(text))
+    }
+
+    pub(crate) fn pretty_print_with_index(
+        &self,
+        f: &mut dyn std::io::Write,
+        file_name: &str,
+        text: &str,
+        index: &LineIndex,
     ) -> std::io::Result<()> {
         pretty_print(
             f,
             file_name,
             text,
+            index,
             self.span(),
             self.message.as_ref(),
             &SchemaErrorColorer {},
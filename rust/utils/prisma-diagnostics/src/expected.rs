@@ -0,0 +1,86 @@
+/// What the parser expected where it failed, as reported by
+/// [`SchemaError::expected()`](crate::SchemaError::expected).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ExpectedToken {
+    ModelDeclaration,
+    EnumDeclaration,
+    /// A datasource or generator block.
+    ConfigBlock,
+    Field,
+    FieldType,
+    EnumValue,
+    /// A property of a datasource or generator, like `url = ...`.
+    Property,
+    BlockAttribute,
+    FieldAttribute,
+    /// The `(` of an argument list.
+    OpeningParenthesis,
+    Argument,
+    Comma,
+    Comment,
+    Name,
+    Value,
+    Array,
+    Number,
+    String,
+    /// `{` followed by a new line.
+    BlockOpen,
+    BlockClose,
+    ModelKeyword,
+    TypeKeyword,
+    ViewKeyword,
+    EnumKeyword,
+    DatasourceKeyword,
+    GeneratorKeyword,
+    /// The `:` of legacy field declarations.
+    Colon,
+    EndOfSchema,
+}
+
+impl ExpectedToken {
+    /// Describes the token to the user, e.g. "a property like `url = ...`".
+    pub fn description(self) -> &'static str {
+        match self {
+            ExpectedToken::ModelDeclaration => "a model declaration",
+            ExpectedToken::EnumDeclaration => "an enum declaration",
+            ExpectedToken::ConfigBlock => "a datasource or generator block",
+            ExpectedToken::Field => "a field like `name String`",
+            ExpectedToken::FieldType => "a field type",
+            ExpectedToken::EnumValue => "an enum value",
+            ExpectedToken::Property => "a property like `url = ...`",
+            ExpectedToken::BlockAttribute => "a block attribute like `@@id(...)`",
+            ExpectedToken::FieldAttribute => "a field attribute like `@id`",
+            ExpectedToken::OpeningParenthesis => "`(`",
+            ExpectedToken::Argument => "an argument",
+            ExpectedToken::Comma => "`,`",
+            ExpectedToken::Comment => "a comment",
+            ExpectedToken::Name => "a name",
+            ExpectedToken::Value => "a value",
+            ExpectedToken::Array => "an array like `[...]`",
+            ExpectedToken::Number => "a number",
+            ExpectedToken::String => "a string",
+            ExpectedToken::BlockOpen => "`{` followed by a new line",
+            ExpectedToken::BlockClose => "`}`",
+            ExpectedToken::ModelKeyword => "`model`",
+            ExpectedToken::TypeKeyword => "`type`",
+            ExpectedToken::ViewKeyword => "`view`",
+            ExpectedToken::EnumKeyword => "`enum`",
+            ExpectedToken::DatasourceKeyword => "`datasource`",
+            ExpectedToken::GeneratorKeyword => "`generator`",
+            ExpectedToken::Colon => "`:`",
+            ExpectedToken::EndOfSchema => "the end of the schema",
+        }
+    }
+}
+
+/// Describes a list of expected tokens, e.g. "a string, a number or a field type".
+pub(crate) fn describe_expected(expected: &[ExpectedToken]) -> String {
+    let descriptions: Vec<_> = expected.iter().map(|token| token.description()).collect();
+
+    match descriptions.as_slice() {
+        [] => "a valid Prisma schema".to_owned(),
+        [description] => (*description).to_owned(),
+        [init @ .., last] => format!("{} or {last}", init.join(", ")),
+    }
+}
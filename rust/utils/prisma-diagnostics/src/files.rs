@@ -0,0 +1,61 @@
+/// The index of a file in [`SchemaFiles`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FileId(pub u32);
+
+impl FileId {
+    /// The first file, and the only one of single-file schemas.
+    pub const ZERO: FileId = FileId(0);
+}
+
+/// The names and sources of the files of a schema, indexed by [`FileId`].
+#[derive(Debug, Clone, Default)]
+pub struct SchemaFiles {
+    files: Vec<(String, String)>,
+}
+
+impl SchemaFiles {
+    /// The files, in order: the first one gets `FileId(0)`.
+    pub fn new<N: Into<String>, S: Into<String>>(files: impl IntoIterator<Item = (N, S)>) -> Self {
+        SchemaFiles {
+            files: files
+                .into_iter()
+                .map(|(name, source)| (name.into(), source.into()))
+                .collect(),
+        }
+    }
+
+    /// Add a file and return its id.
+    pub fn add(&mut self, name: impl Into<String>, source: impl Into<String>) -> FileId {
+        self.files.push((name.into(), source.into()));
+        FileId(self.files.len() as u32 - 1)
+    }
+
+    /// The name of a file.
+    pub fn name(&self, file_id: FileId) -> &str {
+        &self.files[file_id.0 as usize].0
+    }
+
+    /// The text of a file.
+    pub fn source(&self, file_id: FileId) -> &str {
+        &self.files[file_id.0 as usize].1
+    }
+
+    /// Iterate over the files as `(id, name, source)`.
+    pub fn iter(&self) -> impl Iterator<Item = (FileId, &str, &str)> {
+        self.files
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, source))| (FileId(idx as u32), name.as_str(), source.as_str()))
+    }
+
+    /// The number of files.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Are there no files?
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
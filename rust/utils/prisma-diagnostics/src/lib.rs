@@ -1,10 +1,16 @@
 mod collection;
 mod error;
+mod expected;
+mod files;
+mod line_index;
 mod pretty_print;
 mod span;
 mod warning;
 
 pub use collection::Diagnostics;
 pub use error::SchemaError;
+pub use expected::ExpectedToken;
+pub use files::{FileId, SchemaFiles};
+pub use line_index::{Encoding, LineCol, LineIndex};
 pub use span::Span;
 pub use warning::SchemaWarning;
@@ -0,0 +1,243 @@
+use std::ops::Range;
+
+/// The unit of the columns of a [`LineCol`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Encoding {
+    /// Bytes.
+    #[default]
+    Utf8,
+    /// UTF-16 code units, as in JavaScript strings and most editors.
+    Utf16,
+    /// Unicode scalar values, i.e. Rust `char`s.
+    Utf32,
+}
+
+/// A zero-based line and column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    pub line: u32,
+    pub col: u32,
+}
+
+/// Converts byte offsets in a source to lines and columns, and back.
+///
+/// Build it once per source: the conversions do not scan the text again. Lines
+/// end with `\n`, `\r\n` or a lone `\r`, like in the grammar, and the line break is
+/// not part of the columns.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    /// The offset of the start of each line.
+    line_starts: Vec<usize>,
+    /// The offset of the end of each line, before its line break.
+    line_ends: Vec<usize>,
+    /// The non-ASCII characters of each line, as (offset in the line, length in bytes).
+    wide_chars: Vec<Vec<(usize, usize)>>,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let mut line_starts = vec![0];
+        let mut line_ends = Vec::new();
+        let mut wide_chars = vec![Vec::new()];
+
+        for (offset, c) in text.char_indices() {
+            let line_start = *line_starts.last().unwrap();
+            let lone_cr = c == '\r' && !text[offset + 1..].starts_with('\n');
+
+            if c == '\n' || lone_cr {
+                let before_cr = text[..offset].strip_suffix('\r').map_or(offset, str::len);
+                line_ends.push(before_cr.max(line_start));
+                line_starts.push(offset + 1);
+                wide_chars.push(Vec::new());
+            } else if !c.is_ascii() {
+                wide_chars
+                    .last_mut()
+                    .unwrap()
+                    .push((offset - line_start, c.len_utf8()));
+            }
+        }
+
+        line_ends.push(text.len());
+
+        LineIndex {
+            line_starts,
+            line_ends,
+            wide_chars,
+        }
+    }
+
+    /// The number of lines. An empty source has one empty line.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// The byte range of a zero-based line, without its line break.
+    pub fn line_range(&self, line: usize) -> Option<Range<usize>> {
+        Some(*self.line_starts.get(line)?..self.line_ends[line])
+    }
+
+    /// The line and column of a byte offset. Offsets past the end of the
+    /// source are clamped to it, and offsets inside a character to its start.
+    pub fn line_col(&self, offset: usize, encoding: Encoding) -> LineCol {
+        let offset = offset.min(*self.line_ends.last().unwrap());
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let col = offset.min(self.line_ends[line]) - self.line_starts[line];
+
+        LineCol {
+            line: line as u32,
+            col: self.encoded_col(line, col, encoding) as u32,
+        }
+    }
+
+    /// The byte offset of a line and column. Columns past the end of the line
+    /// are clamped to it, and columns inside a character to its start. Lines past
+    /// the end of the source give `None`.
+    pub fn offset(&self, line_col: LineCol, encoding: Encoding) -> Option<usize> {
+        let line = line_col.line as usize;
+        let start = *self.line_starts.get(line)?;
+        let len = self.line_ends[line] - start;
+
+        Some(
+            start
+                + self
+                    .byte_col(line, line_col.col as usize, encoding)
+                    .min(len),
+        )
+    }
+
+    /// Convert a byte column in a line to the given encoding.
+    fn encoded_col(&self, line: usize, col: usize, encoding: Encoding) -> usize {
+        let mut out = col;
+
+        for &(start, len) in &self.wide_chars[line] {
+            if start >= col {
+                break;
+            }
+
+            // `col` is inside this character.
+            if col < start + len {
+                return out - (col - start);
+            }
+            out -= len - encoded_len(len, encoding);
+        }
+
+        out
+    }
+
+    /// Convert a column in the given encoding to a byte column in a line. A
+    /// column inside a character, e.g. between the two UTF-16 units of an emoji,
+    /// gives the start of the character.
+    fn byte_col(&self, line: usize, col: usize, encoding: Encoding) -> usize {
+        let mut out = col;
+
+        for &(start, len) in &self.wide_chars[line] {
+            // `start` is in bytes, `out` is in bytes up to this character.
+            if start >= out {
+                break;
+            }
+
+            let encoded = encoded_len(len, encoding);
+            if out < start + encoded {
+                return start;
+            }
+            out += len - encoded;
+        }
+
+        out
+    }
+}
+
+/// The length in the given encoding of a character of `len_utf8` bytes.
+fn encoded_len(len_utf8: usize, encoding: Encoding) -> usize {
+    match encoding {
+        Encoding::Utf8 => len_utf8,
+        Encoding::Utf16 if len_utf8 == 4 => 2,
+        Encoding::Utf16 | Encoding::Utf32 => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lc(line: u32, col: u32) -> LineCol {
+        LineCol { line, col }
+    }
+
+    #[test]
+    fn line_col_test() {
+        // 'é' is 2 bytes and 1 UTF-16 unit, '😀' is 4 bytes and 2 UTF-16 units.
+        let text = "model A {\r\n  // é😀x\r\n}\nlast";
+        let index = LineIndex::new(text);
+        let x = text.find('x').unwrap();
+
+        assert_eq!(index.line_count(), 4);
+        assert_eq!(&text[index.line_range(1).unwrap()], "  // é😀x");
+        assert_eq!(index.line_col(0, Encoding::Utf16), lc(0, 0));
+        assert_eq!(index.line_col(x, Encoding::Utf8), lc(1, 11));
+        assert_eq!(index.line_col(x, Encoding::Utf16), lc(1, 8));
+        assert_eq!(index.line_col(x, Encoding::Utf32), lc(1, 7));
+
+        // The `\r` of a `\r\n` is the end of the line.
+        assert_eq!(index.line_col(9, Encoding::Utf16), lc(0, 9));
+        assert_eq!(index.line_col(text.len() + 10, Encoding::Utf16), lc(3, 4));
+
+        for encoding in [Encoding::Utf8, Encoding::Utf16, Encoding::Utf32] {
+            for (offset, _) in text.char_indices().chain([(text.len(), ' ')]) {
+                // The `\n` of a `\r\n` maps back to the `\r`.
+                if text[offset..].starts_with('\n') && text[..offset].ends_with('\r') {
+                    continue;
+                }
+
+                let line_col = index.line_col(offset, encoding);
+                assert_eq!(
+                    index.offset(line_col, encoding),
+                    Some(offset),
+                    "{encoding:?}"
+                );
+            }
+        }
+
+        assert_eq!(index.offset(lc(1, 100), Encoding::Utf16), Some(x + 1));
+        assert_eq!(index.offset(lc(4, 0), Encoding::Utf16), None);
+    }
+
+    #[test]
+    fn offset_inside_character_test() {
+        let index = LineIndex::new("a😀b");
+
+        // Between the two UTF-16 units of the emoji.
+        assert_eq!(index.offset(lc(0, 2), Encoding::Utf16), Some(1));
+        assert_eq!(index.offset(lc(0, 3), Encoding::Utf16), Some(5));
+        // Inside its UTF-8 bytes.
+        assert_eq!(index.offset(lc(0, 3), Encoding::Utf8), Some(1));
+    }
+
+    #[test]
+    fn lone_carriage_return_test() {
+        let text = "a\rb\r\nc\r";
+        let index = LineIndex::new(text);
+
+        assert_eq!(index.line_count(), 4);
+        assert_eq!(&text[index.line_range(0).unwrap()], "a");
+        assert_eq!(&text[index.line_range(1).unwrap()], "b");
+        assert_eq!(&text[index.line_range(2).unwrap()], "c");
+        assert_eq!(&text[index.line_range(3).unwrap()], "");
+        assert_eq!(index.line_col(2, Encoding::Utf16), lc(1, 0));
+        assert_eq!(index.offset(lc(2, 0), Encoding::Utf16), Some(5));
+    }
+
+    #[test]
+    fn line_col_inside_character_test() {
+        let index = LineIndex::new("a😀b");
+
+        for offset in 1..5 {
+            assert_eq!(index.line_col(offset, Encoding::Utf16), lc(0, 1));
+            assert_eq!(index.line_col(offset, Encoding::Utf32), lc(0, 1));
+            assert_eq!(index.line_col(offset, Encoding::Utf8), lc(0, 1));
+        }
+
+        assert_eq!(index.line_col(5, Encoding::Utf16), lc(0, 3));
+        assert_eq!(LineIndex::new("😀").line_col(1, Encoding::Utf16), lc(0, 0));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Span;
+use crate::{Encoding, LineIndex, Span};
 use colored::{ColoredString, Colorize};
 
 pub trait DiagnosticColorer {
@@ -13,24 +13,18 @@ pub(crate) fn pretty_print(
     f: &mut dyn std::io::Write,
     file_name: &str,
     text: &str,
+    index: &LineIndex,
     span: Span,
     description: &str,
     colorer: &'static dyn DiagnosticColorer,
 ) -> std::io::Result<()> {
-    let start_line_number = text[..span.start].matches('\n').count();
-    let end_line_number = text[..span.end].matches('\n').count();
-    let file_lines = text.split('\n').collect::<Vec<&str>>();
+    let start = index.line_col(span.start, Encoding::Utf8);
+    let start_line_number = start.line as usize;
+    let end_line_number = index.line_col(span.end, Encoding::Utf8).line as usize;
 
-    let chars_in_line_before: usize = file_lines[..start_line_number]
-        .iter()
-        .map(|l| l.len())
-        .sum();
-    // Don't forget to count the all the line breaks.
-    let chars_in_line_before = chars_in_line_before + start_line_number;
+    let line = line_text(text, index, start_line_number);
 
-    let line = &file_lines[start_line_number];
-
-    let start_in_line = span.start - chars_in_line_before;
+    let start_in_line = start.col as usize;
     let end_in_line = std::cmp::min(start_in_line + (span.end - span.start), line.len());
 
     let prefix = &line[..start_in_line];
@@ -54,7 +48,7 @@ pub(crate) fn pretty_print(
     writeln!(
         f,
         "{}",
-        format_line_number_with_line(start_line_number, &file_lines)
+        format_line_number_with_line(start_line_number, text, index)
     )?;
     writeln!(
         f,
@@ -64,8 +58,9 @@ pub(crate) fn pretty_print(
         offending,
         suffix
     )?;
-    if offending.len() == 0 {
-        let spacing = " ".repeat(start_in_line);
+    if offending.is_empty() {
+        // Characters, not bytes, so that the caret lines up after non-ASCII text.
+        let spacing = " ".repeat(index.line_col(span.start, Encoding::Utf32).col as usize);
         writeln!(
             f,
             "{}{}{}",
@@ -79,20 +74,30 @@ pub(crate) fn pretty_print(
         writeln!(
             f,
             "{}",
-            format_line_number_with_line(line_number, &file_lines)
+            format_line_number_with_line(line_number, text, index)
         )?;
     }
 
     writeln!(f, "{}", format_line_number(0))
 }
 
-fn format_line_number_with_line(line_number: usize, lines: &[&str]) -> colored::ColoredString {
-    if line_number > 0 && line_number <= lines.len() {
+/// The text of a zero-based line, without its line break.
+fn line_text<'a>(text: &'a str, index: &LineIndex, line: usize) -> &'a str {
+    index.line_range(line).map_or("", |range| &text[range])
+}
+
+/// Print the one-based `line_number`, with the text of that line if it exists.
+fn format_line_number_with_line(
+    line_number: usize,
+    text: &str,
+    index: &LineIndex,
+) -> colored::ColoredString {
+    if line_number > 0 && line_number <= index.line_count() {
         colored::ColoredString::from(
             format!(
                 "{}{}",
                 format_line_number(line_number),
-                lines[line_number - 1]
+                line_text(text, index, line_number - 1)
             )
             .as_str(),
         )
@@ -1,37 +1,84 @@
+use crate::FileId;
+
 /// Represents a location in a schema's text representation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Span {
     pub start: usize,
     pub end: usize,
+    /// The file the span is in.
+    pub file_id: FileId,
 }
 
 impl Span {
-    /// Constructor.
+    /// Constructor, for a span in the first file.
     pub fn new(start: usize, end: usize) -> Span {
-        Span { start, end }
+        Span {
+            start,
+            end,
+            file_id: FileId::ZERO,
+        }
     }
 
     /// Creates a new empty span.
     pub fn empty() -> Span {
-        Span { start: 0, end: 0 }
+        Span::new(0, 0)
+    }
+
+    /// The same span, in the given file.
+    pub fn in_file(self, file_id: FileId) -> Span {
+        Span { file_id, ..self }
     }
 
     /// Is the given position inside the span? (boundaries included)
+    ///
+    /// Only the offsets are compared: the position is assumed to be in the span's file. Use
+    /// `contains_in_file` when it may not be.
     pub fn contains(&self, position: usize) -> bool {
         position >= self.start && position <= self.end
     }
 
-    /// Is the given span overlapping with the current span.
+    /// Is the given position in the given file inside the span? (boundaries included)
+    pub fn contains_in_file(&self, file_id: FileId, position: usize) -> bool {
+        self.file_id == file_id && self.contains(position)
+    }
+
+    /// Move the span by `delta` bytes, e.g. after an edit earlier in the text.
+    pub fn shifted(self, delta: isize) -> Span {
+        Span {
+            start: self.start.saturating_add_signed(delta),
+            end: self.end.saturating_add_signed(delta),
+            ..self
+        }
+    }
+
+    /// Is the given span overlapping with the current span. Spans in different files never
+    /// overlap.
     pub fn overlaps(self, other: Span) -> bool {
-        self.contains(other.start) || self.contains(other.end)
+        self.contains_in_file(other.file_id, other.start)
+            || self.contains_in_file(other.file_id, other.end)
     }
 }
 
 impl From<pest::Span<'_>> for Span {
     fn from(s: pest::Span<'_>) -> Self {
-        Span {
-            start: s.start(),
-            end: s.end(),
-        }
+        Span::new(s.start(), s.end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans_in_different_files_test() {
+        let span = Span::new(10, 20);
+        let other = Span::new(15, 25);
+
+        assert!(span.overlaps(other));
+        assert!(!span.overlaps(other.in_file(FileId(1))));
+        assert!(span.contains_in_file(FileId::ZERO, 12));
+        assert!(!span.contains_in_file(FileId(1), 12));
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
     pretty_print::{pretty_print, DiagnosticColorer},
-    Span,
+    FileId, LineIndex, Span,
 };
 use colored::{ColoredString, Colorize};
 
@@ -29,16 +29,43 @@ impl SchemaWarning {
         self.span
     }
 
+    /// The same warning, moved by `delta` bytes.
+    pub fn shifted(self, delta: isize) -> SchemaWarning {
+        SchemaWarning {
+            span: self.span.shifted(delta),
+            ..self
+        }
+    }
+
+    /// The same warning, in the given file.
+    pub fn in_file(self, file_id: FileId) -> SchemaWarning {
+        SchemaWarning {
+            span: self.span.in_file(file_id),
+            ..self
+        }
+    }
+
     pub fn pretty_print(
         &self,
         f: &mut dyn std::io::Write,
         file_name: &str,
         text: &str,
+    ) -> std::io::Result<()> {
+        self.pretty_print_with_index(f, file_name, text, &LineIndex::new(text))
+    }
+
+    pub(crate) fn pretty_print_with_index(
+        &self,
+        f: &mut dyn std::io::Write,
+        file_name: &str,
+        text: &str,
+        index: &LineIndex,
     ) -> std::io::Result<()> {
         pretty_print(
             f,
             file_name,
             text,
+            index,
             self.span(),
             self.message.as_ref(),
             &SchemaWarningColorer {},
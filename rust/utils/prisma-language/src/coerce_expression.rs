@@ -22,7 +22,11 @@ macro_rules! impl_coercions {
 
 impl_coercions! {
     'a;
+    constant : "constant" => &'a str;
     string : "string" => &'a str;
+    integer : "numeric" => i64;
+    float : "float" => f64;
+    boolean : "boolean" => bool;
 }
 
 /// Fallible coercions of PSL expressions to more specific types.
@@ -31,15 +35,59 @@ pub mod coerce_opt {
 
     use super::*;
 
+    pub fn constant<'a>(expr: &'a ast::Expression) -> Option<&'a str> {
+        expr.as_constant_value().map(|(s, _)| s)
+    }
+
     pub fn string<'a>(expr: &'a ast::Expression) -> Option<&'a str> {
         expr.as_string_value().map(|(s, _)| s)
     }
+
+    pub fn integer(expr: &ast::Expression) -> Option<i64> {
+        expr.as_numeric_value()
+            .and_then(|(num, _)| num.parse().ok())
+    }
+
+    pub fn float(expr: &ast::Expression) -> Option<f64> {
+        expr.as_numeric_value()
+            .and_then(|(num, _)| num.parse().ok())
+    }
+
+    pub fn boolean(expr: &ast::Expression) -> Option<bool> {
+        expr.as_constant_value()
+            .and_then(|(constant, _)| constant.parse().ok())
+    }
+}
+
+/// Coerce an expression to an array of the given type. A single value is interpreted as an
+/// array with one element.
+pub fn coerce_array<'a, T>(
+    expr: &'a ast::Expression,
+    coercion: &dyn Fn(&'a ast::Expression, &mut Diagnostics) -> Option<T>,
+    diagnostics: &mut Diagnostics,
+) -> Option<Vec<T>> {
+    let mut out = Vec::new();
+    let mut is_valid = true;
+
+    match expr {
+        ast::Expression::Array(vals, _) => {
+            for val in vals {
+                match coercion(val, diagnostics) {
+                    Some(val) => out.push(val),
+                    None => is_valid = false,
+                }
+            }
+        }
+        _ => out.push(coercion(expr, diagnostics)?),
+    }
+
+    is_valid.then_some(out)
 }
 
 const fn coerce<'a, T>(
     coercion: impl Fn(&'a ast::Expression) -> Option<T>,
     expected_type: &'static str,
-) -> impl (Fn(&'a ast::Expression, &mut Diagnostics) -> Option<T>) {
+) -> impl Fn(&'a ast::Expression, &mut Diagnostics) -> Option<T> {
     move |expr, diagnostics| match coercion(expr) {
         Some(t) => Some(t),
         None => {
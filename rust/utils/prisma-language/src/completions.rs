@@ -0,0 +1,210 @@
+use crate::{
+    config_properties::{ConfigProperty, DATASOURCE_PROPERTIES, GENERATOR_PROPERTIES},
+    connector::Connector,
+    diagnostics::Diagnostics,
+    schema_ast::{self, ast},
+};
+use ast::{
+    ExpressionPosition, GeneratorPosition, PropertyPosition, SchemaPosition, SourcePosition,
+};
+use serde::Serialize;
+
+/// The top-level block keywords, with their documentation.
+const BLOCK_KEYWORDS: &[(&str, &str)] = &[
+    ("datasource", "The database to connect to."),
+    ("generator", "A generator of code, like the Prisma Client."),
+    ("model", "A table or collection in the database."),
+    ("enum", "A set of allowed values."),
+    ("type", "A composite type, embedded in models."),
+    ("view", "A view in the database."),
+];
+
+/// What a [`CompletionItem`] inserts. It maps to the LSP completion item kinds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CompletionItemKind {
+    Keyword,
+    Property,
+    Value,
+    Function,
+}
+
+/// A suggestion for the text at the cursor.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionItem {
+    /// What the editor shows in the list.
+    pub label: String,
+    pub kind: CompletionItemKind,
+    /// What the editor inserts at the cursor.
+    pub insert_text: String,
+    /// What the suggestion means, in markdown.
+    pub documentation: String,
+}
+
+impl CompletionItem {
+    fn new(
+        label: impl Into<String>,
+        kind: CompletionItemKind,
+        insert_text: impl Into<String>,
+        documentation: impl Into<String>,
+    ) -> Self {
+        CompletionItem {
+            label: label.into(),
+            kind,
+            insert_text: insert_text.into(),
+            documentation: documentation.into(),
+        }
+    }
+}
+
+/// The completions at the given byte offset of a schema. The schema does not need
+/// to be valid: it is usually being edited.
+pub fn completions(schema: &str, offset: usize) -> Vec<CompletionItem> {
+    let ast = schema_ast::parse_schema(schema, &mut Diagnostics::new());
+
+    match ast.find_at_position(offset) {
+        SchemaPosition::TopLevel => BLOCK_KEYWORDS
+            .iter()
+            .map(|(keyword, documentation)| {
+                CompletionItem::new(
+                    *keyword,
+                    CompletionItemKind::Keyword,
+                    format!("{keyword} "),
+                    *documentation,
+                )
+            })
+            .collect(),
+        SchemaPosition::DataSource(
+            source_id,
+            SourcePosition::Source | SourcePosition::Property(_, PropertyPosition::Property),
+        ) => property_completions(DATASOURCE_PROPERTIES, &ast[source_id].properties, offset),
+        SchemaPosition::DataSource(
+            _,
+            SourcePosition::Property(name, PropertyPosition::Value(position)),
+        ) => datasource_value_completions(name, &position),
+        SchemaPosition::Generator(
+            generator_id,
+            GeneratorPosition::Generator
+            | GeneratorPosition::Property(_, PropertyPosition::Property),
+        ) => property_completions(GENERATOR_PROPERTIES, &ast[generator_id].properties, offset),
+        _ => Vec::new(),
+    }
+}
+
+/// The known properties that are not in the block yet. The property at the
+/// cursor does not count, since it is being typed.
+fn property_completions(
+    known: &[ConfigProperty],
+    properties: &[ast::ConfigBlockProperty],
+    offset: usize,
+) -> Vec<CompletionItem> {
+    known
+        .iter()
+        .filter(|known| {
+            !properties
+                .iter()
+                .any(|property| property.name.name == known.name && !property.span.contains(offset))
+        })
+        .map(|known| {
+            CompletionItem::new(
+                known.name,
+                CompletionItemKind::Property,
+                format!("{} = ", known.name),
+                known.documentation,
+            )
+        })
+        .collect()
+}
+
+fn datasource_value_completions(
+    property: &str,
+    position: &ExpressionPosition<'_>,
+) -> Vec<CompletionItem> {
+    // Between the quotes, the quotes are already there.
+    let in_string = matches!(position, ExpressionPosition::StringValue(_));
+
+    match (property, position) {
+        ("provider", ExpressionPosition::Expression | ExpressionPosition::StringValue(_)) => {
+            Connector::ALL
+                .iter()
+                .map(|connector| {
+                    let provider = connector.provider();
+                    let insert_text = if in_string {
+                        provider.to_owned()
+                    } else {
+                        format!("\"{provider}\"")
+                    };
+
+                    CompletionItem::new(
+                        provider,
+                        CompletionItemKind::Value,
+                        insert_text,
+                        format!("Connect to a {} database.", connector.display_name()),
+                    )
+                })
+                .collect()
+        }
+        ("url", ExpressionPosition::Expression) => vec![CompletionItem::new(
+            "env()",
+            CompletionItemKind::Function,
+            "env(\"DATABASE_URL\")",
+            "Read the URL from an environment variable, here `DATABASE_URL`.",
+        )],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(schema: &str, offset: usize) -> Vec<String> {
+        completions(schema, offset)
+            .into_iter()
+            .map(|item| item.label)
+            .collect()
+    }
+
+    #[test]
+    fn completions_test() {
+        // The cursor is after `= ` on lines that are being typed.
+        let schema = concat!(
+            "\ndatasource db {\n",
+            "  provider = \n",
+            "  url      = \n",
+            "}\n\n",
+            "generator client {\n",
+            "  provider = \"prisma-client-js\"\n",
+            "}\n",
+        );
+        let provider = schema.find("provider = ").unwrap() + "provider = ".len();
+        let url = schema.find("url      = ").unwrap() + "url      = ".len();
+
+        assert_eq!(labels(schema, 0)[..2], ["datasource", "generator"]);
+        assert_eq!(labels(schema, provider), ["postgres", "mysql"]);
+        assert_eq!(labels(schema, url), ["env()"]);
+        assert_eq!(
+            completions(schema, url)[0].insert_text,
+            "env(\"DATABASE_URL\")"
+        );
+
+        // Only the properties that are not there yet.
+        let in_generator = schema.rfind("\n}\n").unwrap() + 1;
+        assert_eq!(labels(schema, in_generator), ["output"]);
+        assert_eq!(
+            completions(schema, in_generator)[0].insert_text,
+            "output = "
+        );
+    }
+
+    #[test]
+    fn completions_in_string_test() {
+        let schema = "datasource db {\n  provider = \"\"\n}\n";
+        let items = completions(schema, schema.find("\"\"").unwrap() + 1);
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].insert_text, "postgres");
+        assert_eq!(items[0].kind, CompletionItemKind::Value);
+    }
+}
@@ -0,0 +1,36 @@
+//! The properties of configuration blocks that editors know about.
+
+/// A property of a `datasource` or `generator` block.
+pub(crate) struct ConfigProperty {
+    pub(crate) name: &'static str,
+    /// What the property does, in markdown.
+    pub(crate) documentation: &'static str,
+    /// The values the property accepts, in markdown.
+    pub(crate) accepts: &'static str,
+}
+
+pub(crate) const DATASOURCE_PROPERTIES: &[ConfigProperty] = &[
+    ConfigProperty {
+        name: "provider",
+        documentation: "The database connector to use.",
+        accepts: "A string literal with the name of a connector.",
+    },
+    ConfigProperty {
+        name: "url",
+        documentation: "The connection URL of the database.",
+        accepts: "A string literal, or `env(\"...\")` with the name of an environment variable.",
+    },
+];
+
+pub(crate) const GENERATOR_PROPERTIES: &[ConfigProperty] = &[
+    ConfigProperty {
+        name: "provider",
+        documentation: "The generator to run.",
+        accepts: "A string literal, e.g. `\"prisma-client-js\"`.",
+    },
+    ConfigProperty {
+        name: "output",
+        documentation: "The directory the generated files are written to, relative to the schema.",
+        accepts: "A string literal with a path.",
+    },
+];
@@ -12,7 +12,12 @@ pub struct Datasource {
     pub provider: String,
     pub url: StringFromEnvVar,
     pub url_span: Span,
+    /// The doc comments above the datasource block.
     pub documentation: Option<String>,
+    /// The doc comments above the `provider` property.
+    pub provider_documentation: Option<String>,
+    /// The doc comments above the `url` property.
+    pub url_documentation: Option<String>,
     /// the connector of the active provider
     pub(crate) active_connector: Connector,
 }
@@ -47,6 +52,8 @@ impl std::fmt::Debug for Datasource {
             .field("provider", &self.provider)
             .field("url", &"<url>")
             .field("documentation", &self.documentation)
+            .field("provider_documentation", &self.provider_documentation)
+            .field("url_documentation", &self.url_documentation)
             .field("active_connector", &&"...")
             .finish()
     }
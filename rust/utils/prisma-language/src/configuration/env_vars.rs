@@ -16,7 +16,7 @@ pub struct StringFromEnvVar {
 impl StringFromEnvVar {
     pub(crate) fn coerce(expr: &ast::Expression, diagnostics: &mut Diagnostics) -> Option<Self> {
         match expr {
-            ast::Expression::Function(name, _, _) if name == "env" => {
+            ast::Expression::Function(name, _, _) if name.name == "env" => {
                 let mut errs = Diagnostics::new();
                 match EnvFunction::from_ast(expr, &mut errs) {
                     Some(env_function) => Some(StringFromEnvVar::new_from_env_var(
@@ -75,7 +75,7 @@ struct EnvFunction {
 impl EnvFunction {
     fn from_ast(expr: &ast::Expression, diagnostics: &mut Diagnostics) -> Option<EnvFunction> {
         let args = if let ast::Expression::Function(name, args, _) = &expr {
-            if name == "env" {
+            if name.name == "env" {
                 args
             } else {
                 diagnostics.push_error(SchemaError::new_functional_evaluation_error(
@@ -110,3 +110,36 @@ impl EnvFunction {
         &self.var_name
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_configuration;
+
+    #[test]
+    fn datasource_url_from_env_test() {
+        let schema = r#"datasource db {
+  provider = "postgres"
+  url      = env("DATABASE_URL")
+}
+"#;
+        let config = parse_configuration(schema).unwrap();
+        let url = &config.datasources[0].url;
+
+        assert_eq!(url.as_env_var(), Some("DATABASE_URL"));
+        assert_eq!(url.as_literal(), None);
+    }
+
+    #[test]
+    fn datasource_url_from_unknown_function_test() {
+        let schema = r#"datasource db {
+  provider = "postgres"
+  url      = envv("DATABASE_URL")
+}
+"#;
+        let diagnostics = parse_configuration(schema).unwrap_err();
+
+        assert!(diagnostics.errors()[0].message().contains(
+            r#"Expected a String value, but received functional value `envv("DATABASE_URL")`."#
+        ));
+    }
+}
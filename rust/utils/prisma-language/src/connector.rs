@@ -7,6 +7,10 @@ pub enum Connector {
 }
 
 impl Connector {
+    /// Every connector, in the order editors suggest them.
+    pub(crate) const ALL: [Connector; 2] =
+        [Connector::PostgresConnector, Connector::MySQLConnector];
+
     pub fn new(provider: &str) -> Option<Connector> {
         match provider {
             POSTGRES => Some(Connector::PostgresConnector),
@@ -15,30 +19,37 @@ impl Connector {
         }
     }
 
+    /// The value of the `provider` property that selects this connector.
+    pub(crate) fn provider(&self) -> &'static str {
+        match self {
+            Connector::PostgresConnector => POSTGRES,
+            Connector::MySQLConnector => MYSQL,
+        }
+    }
+
+    /// The name of the database, for humans.
+    pub(crate) fn display_name(&self) -> &'static str {
+        match self {
+            Connector::PostgresConnector => "PostgreSQL",
+            Connector::MySQLConnector => "MySQL",
+        }
+    }
+
+    /// The protocol the connection URLs must start with.
+    pub(crate) fn url_prefix(&self) -> &'static str {
+        match self {
+            Connector::PostgresConnector => "postgres://",
+            Connector::MySQLConnector => "mysql://",
+        }
+    }
+
     pub fn validate_url(&self, url: &str) -> Result<(), String> {
-        let error_prefix: Option<String> = match self {
-            Connector::PostgresConnector => {
-                let expected_prefix = "postgres://";
-                if !url.starts_with(&expected_prefix) {
-                    Some(expected_prefix.to_owned())
-                } else {
-                    None
-                }
-            }
-            Connector::MySQLConnector => {
-                let expected_prefix = "mysql://";
-                if !url.starts_with(&expected_prefix) {
-                    Some(expected_prefix.to_owned())
-                } else {
-                    None
-                }
-            }
-        };
-
-        if let Some(error_prefix) = error_prefix {
+        let expected_prefix = self.url_prefix();
+
+        if !url.starts_with(expected_prefix) {
             Err(format!(
                 "The URL must start with the `{}` protocol.",
-                error_prefix
+                expected_prefix
             ))
         } else {
             Ok(())
@@ -0,0 +1,152 @@
+use crate::schema_ast::ast::{self, Span};
+
+/// What a [`DocumentSymbol`] is. It maps to the LSP symbol kinds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SymbolKind {
+    Datasource,
+    Generator,
+    Model,
+    CompositeType,
+    View,
+    Enum,
+    /// A property of a datasource or generator.
+    Property,
+    /// A field of a model, composite type or view.
+    Field,
+    EnumValue,
+}
+
+/// A node of the outline of a schema.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DocumentSymbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// The whole node, e.g. a block from its keyword to its closing brace.
+    pub span: Span,
+    /// The name of the node, to select when the symbol is picked.
+    pub name_span: Span,
+    /// The nodes inside this one, e.g. the properties of a datasource.
+    pub children: Vec<DocumentSymbol>,
+}
+
+impl DocumentSymbol {
+    fn new(kind: SymbolKind, name: &ast::Identifier, span: Span) -> Self {
+        DocumentSymbol {
+            name: name.name.clone(),
+            kind,
+            span,
+            name_span: name.span,
+            children: Vec::new(),
+        }
+    }
+
+    fn with_children(mut self, children: Vec<DocumentSymbol>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// The outline of a schema: its blocks, in order, with what they contain as
+/// children. Blocks that could not be parsed are left out.
+pub fn document_symbols(ast: &ast::SchemaAst) -> Vec<DocumentSymbol> {
+    ast.tops
+        .iter()
+        .filter_map(|top| match top {
+            ast::Top::Source(source) => Some(
+                DocumentSymbol::new(SymbolKind::Datasource, &source.name, source.span)
+                    .with_children(property_symbols(&source.properties)),
+            ),
+            ast::Top::Generator(generator) => Some(
+                DocumentSymbol::new(SymbolKind::Generator, &generator.name, generator.span)
+                    .with_children(property_symbols(&generator.properties)),
+            ),
+            ast::Top::Model(model) => Some(
+                DocumentSymbol::new(SymbolKind::Model, &model.name, model.span)
+                    .with_children(field_symbols(&model.fields)),
+            ),
+            ast::Top::CompositeType(composite_type) => Some(
+                DocumentSymbol::new(
+                    SymbolKind::CompositeType,
+                    &composite_type.name,
+                    composite_type.span,
+                )
+                .with_children(field_symbols(&composite_type.fields)),
+            ),
+            ast::Top::View(view) => Some(
+                DocumentSymbol::new(SymbolKind::View, &view.name, view.span)
+                    .with_children(field_symbols(&view.fields)),
+            ),
+            ast::Top::Enum(r#enum) => Some(
+                DocumentSymbol::new(SymbolKind::Enum, &r#enum.name, r#enum.span).with_children(
+                    r#enum
+                        .values
+                        .iter()
+                        .map(|value| {
+                            DocumentSymbol::new(SymbolKind::EnumValue, &value.name, value.span)
+                        })
+                        .collect(),
+                ),
+            ),
+            ast::Top::Invalid(_) => None,
+        })
+        .collect()
+}
+
+fn property_symbols(properties: &[ast::ConfigBlockProperty]) -> Vec<DocumentSymbol> {
+    properties
+        .iter()
+        .map(|property| DocumentSymbol::new(SymbolKind::Property, &property.name, property.span))
+        .collect()
+}
+
+fn field_symbols(fields: &[ast::Field]) -> Vec<DocumentSymbol> {
+    fields
+        .iter()
+        .map(|field| DocumentSymbol::new(SymbolKind::Field, &field.name, field.span))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostics::Diagnostics, schema_ast::parse_schema};
+
+    #[test]
+    fn document_symbols_test() {
+        let schema = r#"datasource db {
+  provider = "postgres"
+  url      = env("DATABASE_URL")
+}
+
+model User {
+  id Int @id
+}
+"#;
+        let ast = parse_schema(schema, &mut Diagnostics::new());
+        let symbols = document_symbols(&ast);
+
+        let outline: Vec<(&str, Vec<&str>)> = symbols
+            .iter()
+            .map(|symbol| {
+                let children = symbol.children.iter().map(|c| c.name.as_str()).collect();
+                (symbol.name.as_str(), children)
+            })
+            .collect();
+        assert_eq!(
+            outline,
+            [("db", vec!["provider", "url"]), ("User", vec!["id"])]
+        );
+
+        let datasource = &symbols[0];
+        assert_eq!(datasource.kind, SymbolKind::Datasource);
+        assert_eq!(
+            &schema[datasource.name_span.start..datasource.name_span.end],
+            "db"
+        );
+        assert!(schema[datasource.span.start..datasource.span.end].starts_with("datasource db {"));
+
+        let url = &datasource.children[1];
+        assert_eq!(url.kind, SymbolKind::Property);
+        assert!(schema[url.span.start..url.span.end].starts_with("url      = env("));
+    }
+}
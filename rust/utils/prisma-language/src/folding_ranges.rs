@@ -0,0 +1,135 @@
+use crate::{
+    diagnostics::{Encoding, LineIndex},
+    schema_ast::ast::{self, Span},
+};
+
+/// What a [`FoldingRange`] folds. It maps to the LSP folding range kinds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FoldingRangeKind {
+    /// The body of a block.
+    Region,
+    /// Consecutive comment lines.
+    Comment,
+}
+
+/// Lines an editor can collapse. As in LSP, the lines are zero-based and
+/// inclusive, and the start line stays visible when folded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FoldingRange {
+    pub start_line: u32,
+    pub end_line: u32,
+    pub kind: FoldingRangeKind,
+}
+
+/// The folding ranges of a schema: the bodies of its blocks, from the line
+/// of the opening brace, and the runs of two or more comment lines. `schema` is
+/// the text `ast` was parsed from.
+pub fn folding_ranges(ast: &ast::SchemaAst, schema: &str) -> Vec<FoldingRange> {
+    let index = LineIndex::new(schema);
+    let mut ranges = Vec::new();
+
+    for top in &ast.tops {
+        // The bodies start on the line after the opening brace.
+        let body = match top {
+            ast::Top::Source(source) => source.inner_span,
+            ast::Top::Generator(generator) => generator.inner_span,
+            ast::Top::Enum(r#enum) => r#enum.inner_span,
+            ast::Top::CompositeType(composite_type) => composite_type.inner_span,
+            ast::Top::Model(model) => model.inner_span,
+            ast::Top::View(view) => view.inner_span,
+            ast::Top::Invalid(_) => continue,
+        };
+
+        if let Some(range) = body_lines(&index, body) {
+            push_range(&mut ranges, range.0 - 1, range.1, FoldingRangeKind::Region);
+        }
+    }
+
+    let mut run: Option<(u32, u32)> = None;
+    for line in 0..index.line_count() {
+        let range = index.line_range(line).unwrap();
+        let line = line as u32;
+
+        if schema[range].trim_start().starts_with("//") {
+            run = Some(run.map_or((line, line), |(start, _)| (start, line)));
+        } else if let Some((start, end)) = run.take() {
+            push_range(&mut ranges, start, end, FoldingRangeKind::Comment);
+        }
+    }
+    if let Some((start, end)) = run {
+        push_range(&mut ranges, start, end, FoldingRangeKind::Comment);
+    }
+
+    ranges.sort_by_key(|range| range.start_line);
+    ranges
+}
+
+/// The first and last lines of a block body, if it is not empty.
+fn body_lines(index: &LineIndex, body: Span) -> Option<(u32, u32)> {
+    let start = index.line_col(body.start, Encoding::Utf8);
+    let end = index.line_col(body.end, Encoding::Utf8);
+
+    // The body usually ends with the line break before the closing brace.
+    let last = if end.col == 0 {
+        end.line.checked_sub(1)?
+    } else {
+        end.line
+    };
+
+    (start.line > 0 && last >= start.line).then_some((start.line, last))
+}
+
+/// Add the range, unless it is a single line: there is nothing to fold then.
+fn push_range(
+    ranges: &mut Vec<FoldingRange>,
+    start_line: u32,
+    end_line: u32,
+    kind: FoldingRangeKind,
+) {
+    if end_line > start_line {
+        ranges.push(FoldingRange {
+            start_line,
+            end_line,
+            kind,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostics::Diagnostics, schema_ast::parse_schema};
+
+    #[test]
+    fn folding_ranges_test() {
+        let schema = r#"// A schema
+// in two lines.
+datasource db {
+  provider = "postgres"
+  url      = env("DATABASE_URL")
+}
+
+/// Users.
+model User {
+  id Int @id
+}
+
+enum Empty {
+}
+"#;
+        let ast = parse_schema(schema, &mut Diagnostics::new());
+        let ranges: Vec<_> = folding_ranges(&ast, schema)
+            .into_iter()
+            .map(|range| (range.start_line, range.end_line, range.kind))
+            .collect();
+
+        assert_eq!(
+            ranges,
+            [
+                (0, 1, FoldingRangeKind::Comment),
+                (2, 4, FoldingRangeKind::Region),
+                (8, 9, FoldingRangeKind::Region),
+            ]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    config_properties::{ConfigProperty, DATASOURCE_PROPERTIES, GENERATOR_PROPERTIES},
+    connector::Connector,
+    diagnostics::Diagnostics,
+    schema_ast::{self, ast},
+    validate_configuration, Datasource,
+};
+use ast::{
+    ExpressionPosition, GeneratorPosition, PropertyPosition, SchemaPosition, SourcePosition,
+    WithDocumentation,
+};
+
+/// Reads an environment variable, like in [`Datasource::load_url()`].
+type Env<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// The hover text for the node at the given byte offset of a schema, in markdown.
+pub fn hover(schema: &str, offset: usize) -> Option<String> {
+    hover_impl(schema, offset, None)
+}
+
+/// Like [`hover()`], and also tells whether the variables of `env("...")` calls
+/// are set in `env`.
+pub fn hover_with_env<F>(schema: &str, offset: usize, env: F) -> Option<String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    hover_impl(schema, offset, Some(&env))
+}
+
+fn hover_impl(schema: &str, offset: usize, env: Option<Env<'_>>) -> Option<String> {
+    let mut diagnostics = Diagnostics::new();
+    let ast = schema_ast::parse_schema(schema, &mut diagnostics);
+
+    match ast.find_at_position(offset) {
+        SchemaPosition::DataSource(source_id, position) => {
+            let source = &ast[source_id];
+
+            match position {
+                SourcePosition::Name(_) => {
+                    let configuration = validate_configuration(&ast, &mut diagnostics);
+                    let datasource = configuration
+                        .datasources
+                        .iter()
+                        .find(|datasource| datasource.name == source.name.name);
+
+                    Some(datasource_hover(source, datasource))
+                }
+                SourcePosition::Property(name, position) => {
+                    let property = source
+                        .properties
+                        .iter()
+                        .find(|property| property.span.contains(offset))?;
+
+                    let on_provider =
+                        name == "provider" && matches!(position, PropertyPosition::Property);
+                    let mut hover =
+                        property_hover(DATASOURCE_PROPERTIES, property, name, position, env)?;
+
+                    if on_provider {
+                        let providers: Vec<_> = Connector::ALL
+                            .iter()
+                            .map(|connector| format!("`\"{}\"`", connector.provider()))
+                            .collect();
+                        hover.push_str(&format!("\n\n**Providers:** {}", providers.join(", ")));
+                    }
+
+                    Some(hover)
+                }
+                _ => None,
+            }
+        }
+        SchemaPosition::Generator(generator_id, GeneratorPosition::Property(name, position)) => {
+            let property = ast[generator_id]
+                .properties
+                .iter()
+                .find(|property| property.span.contains(offset))?;
+
+            property_hover(GENERATOR_PROPERTIES, property, name, position, env)
+        }
+        _ => None,
+    }
+}
+
+fn datasource_hover(source: &ast::SourceConfig, datasource: Option<&Datasource>) -> String {
+    let mut sections = vec![format!("```prisma\ndatasource {}\n```", source.name.name)];
+
+    if let Some(documentation) = source.documentation() {
+        sections.push(documentation.to_owned());
+    }
+
+    // Only a valid datasource has a connector.
+    if let Some(datasource) = datasource {
+        let connector = &datasource.active_connector;
+        sections.push(format!(
+            "**Provider:** {} (`{}`)",
+            connector.display_name(),
+            connector.provider()
+        ));
+    }
+
+    sections.join("\n\n")
+}
+
+fn property_hover(
+    known: &[ConfigProperty],
+    property: &ast::ConfigBlockProperty,
+    name: &str,
+    position: PropertyPosition<'_>,
+    env: Option<Env<'_>>,
+) -> Option<String> {
+    match position {
+        PropertyPosition::Property => {
+            let known = known.iter().find(|known| known.name == name)?;
+            let mut sections = vec![
+                format!("```prisma\n{name}\n```"),
+                known.documentation.to_owned(),
+            ];
+
+            if let Some(documentation) = property.documentation() {
+                sections.push(documentation.to_owned());
+            }
+
+            sections.push(format!("**Accepts:** {}", known.accepts));
+
+            Some(sections.join("\n\n"))
+        }
+        PropertyPosition::Value(
+            ExpressionPosition::Function("env") | ExpressionPosition::FunctionArgument("env", ..),
+        ) => {
+            let (_, args, _) = property.value.as_ref()?.as_function()?;
+            let (var_name, _) = args.arguments.first()?.value.as_string_value()?;
+            let mut hover = format!("**Environment variable:** `{var_name}`");
+
+            if let Some(env) = env {
+                let status = if env(var_name).is_some() {
+                    "It is set."
+                } else {
+                    "It is not set."
+                };
+                hover.push_str("\n\n");
+                hover.push_str(status);
+            }
+
+            Some(hover)
+        }
+        PropertyPosition::Value(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"/// The main database.
+datasource db {
+  /// Which database.
+  provider = "postgres"
+  url      = env("DATABASE_URL")
+}
+"#;
+
+    fn offset(needle: &str) -> usize {
+        SCHEMA.find(needle).unwrap() + 1
+    }
+
+    #[test]
+    fn hover_datasource_test() {
+        let text = hover(SCHEMA, offset("db {")).unwrap();
+
+        assert_eq!(
+            text,
+            "```prisma\ndatasource db\n```\n\nThe main database.\n\n**Provider:** PostgreSQL (`postgres`)"
+        );
+    }
+
+    #[test]
+    fn hover_property_test() {
+        let text = hover(SCHEMA, offset("provider")).unwrap();
+
+        assert!(text.starts_with(
+            "```prisma\nprovider\n```\n\nThe database connector to use.\n\nWhich database.\n\n"
+        ));
+        assert!(text.ends_with("**Providers:** `\"postgres\"`, `\"mysql\"`"));
+        assert!(!hover(SCHEMA, offset("url")).unwrap().contains("Providers"));
+        assert_eq!(hover(SCHEMA, offset("\"postgres\"")), None);
+    }
+
+    #[test]
+    fn hover_env_test() {
+        let position = offset("DATABASE_URL");
+
+        assert_eq!(
+            hover(SCHEMA, position).unwrap(),
+            "**Environment variable:** `DATABASE_URL`"
+        );
+        assert!(
+            hover_with_env(SCHEMA, position, |_| Some("postgres://".to_owned()))
+                .unwrap()
+                .ends_with("It is set.")
+        );
+        assert!(hover_with_env(SCHEMA, offset("env("), |_| None)
+            .unwrap()
+            .ends_with("It is not set."));
+    }
+}
@@ -2,20 +2,33 @@
 
 /// Fallible coercions of PSL expressions to more specific types.
 mod coerce_expression;
+mod completions;
+mod config_properties;
 mod configuration;
 mod connector;
+mod document_symbols;
+mod folding_ranges;
+mod hover;
 mod validate;
 
-pub use crate::configuration::{
-    Configuration, Datasource, DatasourceConnectorData, StringFromEnvVar,
+pub use crate::{
+    coerce_expression::{coerce, coerce_array, coerce_opt},
+    completions::{completions, CompletionItem, CompletionItemKind},
+    configuration::{Configuration, Datasource, DatasourceConnectorData, StringFromEnvVar},
+    document_symbols::{document_symbols, DocumentSymbol, SymbolKind},
+    folding_ranges::{folding_ranges, FoldingRange, FoldingRangeKind},
+    hover::{hover, hover_with_env},
 };
 pub(crate) use prisma_diagnostics as diagnostics;
 pub(crate) use prisma_parser as schema_ast;
 
 use self::validate::datasource_loader;
-use diagnostics::Diagnostics;
+use diagnostics::{Diagnostics, SchemaFiles};
 use schema_ast::ast;
 
+/// The file name in the errors of single-file schemas.
+const SCHEMA_FILE_NAME: &str = "schema.prisma";
+
 pub struct ValidatedSchema {
     pub configuration: Configuration,
     pub diagnostics: Diagnostics,
@@ -30,8 +43,13 @@ impl std::fmt::Debug for ValidatedSchema {
 /// The most general API for dealing with Prisma schemas. It accumulates what analysis and
 /// validation information it can, and returns it along with any error and warning diagnostics.
 pub fn validate(file: &str) -> ValidatedSchema {
+    validate_files(&[(SCHEMA_FILE_NAME, file)])
+}
+
+/// Like [`validate()`], for a schema split across several `(name, source)` files.
+pub fn validate_files<N: AsRef<str>, S: AsRef<str>>(files: &[(N, S)]) -> ValidatedSchema {
     let mut diagnostics = Diagnostics::new();
-    let ast = schema_ast::parse_schema(file, &mut diagnostics);
+    let ast = schema_ast::parse_schemas(files, &mut diagnostics);
     let configuration = validate_configuration(&ast, &mut diagnostics);
 
     ValidatedSchema {
@@ -42,11 +60,25 @@ pub fn validate(file: &str) -> ValidatedSchema {
 
 /// Parse and analyze a Prisma schema.
 pub fn parse_schema(file: &str) -> Result<ValidatedSchema, String> {
-    let mut schema = validate(file.into());
+    parse_schemas(&[(SCHEMA_FILE_NAME, file)])
+}
+
+/// Parse and analyze a Prisma schema split across several `(name, source)` files.
+/// Each error is rendered against the file it is in.
+pub fn parse_schemas<N: AsRef<str>, S: AsRef<str>>(
+    files: &[(N, S)],
+) -> Result<ValidatedSchema, String> {
+    let mut schema = validate_files(files);
+    let files = SchemaFiles::new(
+        files
+            .iter()
+            .map(|(name, source)| (name.as_ref(), source.as_ref())),
+    );
+
     schema
         .diagnostics
         .to_result()
-        .map_err(|err| err.to_pretty_string("schema.prisma", file))?;
+        .map_err(|err| err.to_pretty_string_in_files(&files))?;
     Ok(schema)
 }
 
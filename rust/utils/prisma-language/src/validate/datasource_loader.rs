@@ -138,6 +138,14 @@ fn lift_datasource(
 
     use prisma_parser::ast::WithDocumentation;
     let documentation = ast_source.documentation().map(String::from);
+    let property_documentation = |key: &str| {
+        ast_source
+            .properties
+            .iter()
+            .find(|property| property.name.name == key)
+            .and_then(|property| property.documentation())
+            .map(String::from)
+    };
 
     for (name, (span, _)) in args.into_iter() {
         diagnostics.push_error(SchemaError::new_property_not_known_error(name, span));
@@ -149,6 +157,36 @@ fn lift_datasource(
         url,
         url_span,
         documentation,
+        provider_documentation: property_documentation(PROVIDER_KEY),
+        url_documentation: property_documentation(URL_KEY),
         active_connector,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_configuration;
+
+    #[test]
+    fn datasource_documentation_test() {
+        let schema = r#"/// The main database.
+datasource db {
+  /// Which database.
+  provider = "postgres"
+  url      = env("DATABASE_URL")
+}
+"#;
+        let config = parse_configuration(schema).unwrap();
+        let datasource = &config.datasources[0];
+
+        assert_eq!(
+            datasource.documentation.as_deref(),
+            Some("The main database.")
+        );
+        assert_eq!(
+            datasource.provider_documentation.as_deref(),
+            Some("Which database.")
+        );
+        assert_eq!(datasource.url_documentation, None);
+    }
+}
@@ -1,28 +1,53 @@
 mod argument;
+mod attribute;
+mod builder;
 mod comment;
+mod composite_type;
 mod config;
+mod r#enum;
 mod expression;
+mod field;
 mod find_at_position;
+mod generator_config;
 mod identifier;
 mod indentation_type;
+mod invalid_block;
+mod model;
 mod newline_type;
+mod render;
 mod source_config;
 mod top;
 mod traits;
+mod view;
 
-pub(crate) use self::comment::Comment;
+pub mod visit;
 
-pub use crate::diagnostics::Span;
+pub use crate::diagnostics::{FileId, Span};
 pub use argument::{Argument, ArgumentsList, EmptyArgument};
+pub use attribute::Attribute;
+pub use builder::{
+    CompositeTypeBuilder, EnumBuilder, FieldBuilder, GeneratorConfigBuilder, ModelBuilder,
+    NumericLiteral, SchemaAstBuilder, SourceConfigBuilder, ViewBuilder,
+};
+pub use comment::Comment;
+pub use composite_type::CompositeType;
 pub use config::ConfigBlockProperty;
 pub use expression::Expression;
+pub use field::{Field, FieldArity, FieldType};
 pub use find_at_position::*;
+pub use generator_config::GeneratorConfig;
 pub use identifier::Identifier;
 pub use indentation_type::IndentationType;
+pub use invalid_block::InvalidBlock;
+pub use model::{FieldId, Model};
 pub use newline_type::NewlineType;
+pub use r#enum::{Enum, EnumValue, EnumValueId};
 pub use source_config::SourceConfig;
 pub use top::Top;
-pub use traits::{WithDocumentation, WithIdentifier, WithName, WithSpan};
+pub use traits::{
+    WithAttributes, WithDocumentation, WithIdentifier, WithName, WithSpan, WithSyntax,
+};
+pub use view::View;
 
 /// AST representation of a prisma schema.
 ///
@@ -34,9 +59,14 @@ pub use traits::{WithDocumentation, WithIdentifier, WithName, WithSpan};
 /// Basically, the AST is an object oriented representation of the schema's text.
 /// Schema = Schema + Generators + Datasources
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct SchemaAst {
-    /// All models, enums, composite types, datasources, generators and type aliases.
+    /// All models, views, enums, composite types, datasources, generators and the blocks
+    /// that could not be parsed, ordered by file and then by position.
     pub tops: Vec<Top>,
+    /// The top-level comments that do not document a block, one per line.
+    pub comments: Vec<Comment>,
 }
 
 impl SchemaAst {
@@ -48,16 +78,103 @@ impl SchemaAst {
             .map(|(top_idx, top)| (top_idx_to_top_id(top_idx, top), top))
     }
 
+    /// Iterate over all the models in the schema.
+    pub fn models(&self) -> impl Iterator<Item = &Model> {
+        self.tops.iter().filter_map(|top| top.as_model())
+    }
+
+    /// Iterate over all the composite types in the schema.
+    pub fn composite_types(&self) -> impl Iterator<Item = &CompositeType> {
+        self.tops.iter().filter_map(|top| top.as_composite_type())
+    }
+
+    /// Iterate over all the views in the schema.
+    pub fn views(&self) -> impl Iterator<Item = &View> {
+        self.tops.iter().filter_map(|top| top.as_view())
+    }
+
+    /// Iterate over all the enums in the schema.
+    pub fn enums(&self) -> impl Iterator<Item = &Enum> {
+        self.tops.iter().filter_map(|top| top.as_enum())
+    }
+
+    /// Iterate over all the generator blocks in the schema.
+    pub fn generators(&self) -> impl Iterator<Item = &GeneratorConfig> {
+        self.tops.iter().filter_map(|top| top.as_generator())
+    }
+
     /// Iterate over all the datasource blocks in the schema.
     pub fn sources(&self) -> impl Iterator<Item = &SourceConfig> {
         self.tops.iter().filter_map(|top| top.as_source())
     }
+
+    /// Iterate over all the blocks that could not be parsed.
+    pub fn invalid_blocks(&self) -> impl Iterator<Item = &InvalidBlock> {
+        self.tops.iter().filter_map(|top| top.as_invalid_block())
+    }
+}
+
+/// An opaque identifier for a model in a schema AST. Use the
+/// `schema[model_id]` syntax to resolve the id to an `ast::Model`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModelId(u32);
+
+impl std::ops::Index<ModelId> for SchemaAst {
+    type Output = Model;
+
+    fn index(&self, index: ModelId) -> &Self::Output {
+        self.tops[index.0 as usize].as_model().unwrap()
+    }
+}
+
+/// An opaque identifier for a composite type in a schema AST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompositeTypeId(u32);
+
+impl std::ops::Index<CompositeTypeId> for SchemaAst {
+    type Output = CompositeType;
+
+    fn index(&self, index: CompositeTypeId) -> &Self::Output {
+        self.tops[index.0 as usize].as_composite_type().unwrap()
+    }
+}
+
+/// An opaque identifier for a view in a schema AST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ViewId(u32);
+
+impl std::ops::Index<ViewId> for SchemaAst {
+    type Output = View;
+
+    fn index(&self, index: ViewId) -> &Self::Output {
+        self.tops[index.0 as usize].as_view().unwrap()
+    }
+}
+
+/// An opaque identifier for an enum in a schema AST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EnumId(u32);
+
+impl std::ops::Index<EnumId> for SchemaAst {
+    type Output = Enum;
+
+    fn index(&self, index: EnumId) -> &Self::Output {
+        self.tops[index.0 as usize].as_enum().unwrap()
+    }
 }
 
 /// An opaque identifier for a generator block in a schema AST.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct GeneratorId(u32);
 
+impl std::ops::Index<GeneratorId> for SchemaAst {
+    type Output = GeneratorConfig;
+
+    fn index(&self, index: GeneratorId) -> &Self::Output {
+        self.tops[index.0 as usize].as_generator().unwrap()
+    }
+}
+
 /// An opaque identifier for a datasource block in a schema AST.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SourceId(u32);
@@ -70,12 +187,70 @@ impl std::ops::Index<SourceId> for SchemaAst {
     }
 }
 
+/// An opaque identifier for a block that could not be parsed in a schema AST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InvalidBlockId(u32);
+
+impl std::ops::Index<InvalidBlockId> for SchemaAst {
+    type Output = InvalidBlock;
+
+    fn index(&self, index: InvalidBlockId) -> &Self::Output {
+        self.tops[index.0 as usize].as_invalid_block().unwrap()
+    }
+}
+
 /// An identifier for a top-level item in a schema AST. Use the `schema[top_id]`
 /// syntax to resolve the id to an `ast::Top`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TopId {
+    /// A model declaration
+    Model(ModelId),
+    /// A composite type declaration
+    CompositeType(CompositeTypeId),
+    /// A view declaration
+    View(ViewId),
+    /// An enum declaration
+    Enum(EnumId),
+    /// A generator block
+    Generator(GeneratorId),
     /// A datasource block
     Source(SourceId),
+    /// A block that could not be parsed
+    Invalid(InvalidBlockId),
+}
+
+impl TopId {
+    /// Try to interpret the top as a composite type.
+    pub fn as_composite_type_id(self) -> Option<CompositeTypeId> {
+        match self {
+            TopId::CompositeType(composite_type_id) => Some(composite_type_id),
+            _ => None,
+        }
+    }
+
+    /// Try to interpret the top as a view.
+    pub fn as_view_id(self) -> Option<ViewId> {
+        match self {
+            TopId::View(view_id) => Some(view_id),
+            _ => None,
+        }
+    }
+
+    /// Try to interpret the top as an enum.
+    pub fn as_enum_id(self) -> Option<EnumId> {
+        match self {
+            TopId::Enum(enum_id) => Some(enum_id),
+            _ => None,
+        }
+    }
+
+    /// Try to interpret the top as a model.
+    pub fn as_model_id(self) -> Option<ModelId> {
+        match self {
+            TopId::Model(model_id) => Some(model_id),
+            _ => None,
+        }
+    }
 }
 
 impl std::ops::Index<TopId> for SchemaAst {
@@ -83,7 +258,13 @@ impl std::ops::Index<TopId> for SchemaAst {
 
     fn index(&self, index: TopId) -> &Self::Output {
         let idx = match index {
+            TopId::Model(ModelId(idx)) => idx,
+            TopId::CompositeType(CompositeTypeId(idx)) => idx,
+            TopId::View(ViewId(idx)) => idx,
+            TopId::Enum(EnumId(idx)) => idx,
+            TopId::Generator(GeneratorId(idx)) => idx,
             TopId::Source(SourceId(idx)) => idx,
+            TopId::Invalid(InvalidBlockId(idx)) => idx,
         };
 
         &self.tops[idx as usize]
@@ -92,6 +273,12 @@ impl std::ops::Index<TopId> for SchemaAst {
 
 fn top_idx_to_top_id(top_idx: usize, top: &Top) -> TopId {
     match top {
+        Top::Model(_) => TopId::Model(ModelId(top_idx as u32)),
+        Top::CompositeType(_) => TopId::CompositeType(CompositeTypeId(top_idx as u32)),
+        Top::View(_) => TopId::View(ViewId(top_idx as u32)),
+        Top::Enum(_) => TopId::Enum(EnumId(top_idx as u32)),
+        Top::Generator(_) => TopId::Generator(GeneratorId(top_idx as u32)),
         Top::Source(_) => TopId::Source(SourceId(top_idx as u32)),
+        Top::Invalid(_) => TopId::Invalid(InvalidBlockId(top_idx as u32)),
     }
 }
@@ -3,6 +3,8 @@ use std::fmt::{Display, Formatter};
 
 /// A list of arguments inside parentheses.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ArgumentsList {
     /// The arguments themselves.
     ///
@@ -35,6 +37,8 @@ impl ArgumentsList {
 
 /// An argument, either for attributes or for function call expressions.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Argument {
     /// The argument name, if applicable.
     ///
@@ -85,6 +89,8 @@ impl WithSpan for Argument {
 /// This is of course invalid, but we parse it in order to provide better diagnostics and
 /// for autocompletion.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct EmptyArgument {
     pub name: Identifier,
 }
@@ -0,0 +1,47 @@
+use super::{ArgumentsList, Identifier, Span, WithIdentifier, WithSpan};
+
+/// An attribute (following `@` or `@@`) on a model, view, field, enum or enum value.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Attribute {
+    /// The name of the attribute:
+    ///
+    /// ```ignore
+    /// @@index([a, b, c])
+    ///   ^^^^^
+    /// ```
+    pub name: Identifier,
+    /// The arguments of the attribute.
+    ///
+    /// ```ignore
+    /// @@index([a, b, c], map: "myidix")
+    ///         ^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    pub arguments: ArgumentsList,
+    /// The AST span of the node.
+    pub span: Span,
+}
+
+impl Attribute {
+    /// Try to find the argument and return its span.
+    pub fn span_for_argument(&self, argument: &str) -> Option<Span> {
+        self.arguments
+            .iter()
+            .filter(|a| a.name.as_ref().map(|n| n.name.as_str()) == Some(argument))
+            .map(|a| a.span)
+            .next()
+    }
+}
+
+impl WithIdentifier for Attribute {
+    fn identifier(&self) -> &Identifier {
+        &self.name
+    }
+}
+
+impl WithSpan for Attribute {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
@@ -0,0 +1,549 @@
+//! Building an AST in code, e.g. to generate a schema from a template.
+//!
+//! Built nodes have empty spans. Print them with their `Display` impl:
+//!
+//! ```
+//! use prisma_parser::ast::{Expression, GeneratorConfig, SchemaAst, SourceConfig};
+//!
+//! let schema = SchemaAst::builder()
+//!     .top(
+//!         SourceConfig::builder("db")
+//!             .property("provider", Expression::string("postgres"))
+//!             .property(
+//!                 "url",
+//!                 Expression::function("env", vec![Expression::string("DATABASE_URL")]),
+//!             )
+//!             .build(),
+//!     )
+//!     .top(
+//!         GeneratorConfig::builder("client")
+//!             .property("provider", Expression::string("prisma-client-js"))
+//!             .build(),
+//!     )
+//!     .build();
+//!
+//! assert!(schema.to_string().starts_with("datasource db {\n  provider = \"postgres\"\n"));
+//! ```
+
+use super::*;
+
+impl SchemaAst {
+    /// Start building a schema.
+    pub fn builder() -> SchemaAstBuilder {
+        SchemaAstBuilder {
+            tops: Vec::new(),
+            comments: Vec::new(),
+        }
+    }
+}
+
+/// Builds a [`SchemaAst`]. Created with [`SchemaAst::builder()`].
+#[derive(Debug)]
+pub struct SchemaAstBuilder {
+    tops: Vec<Top>,
+    comments: Vec<Comment>,
+}
+
+impl SchemaAstBuilder {
+    /// Add a block.
+    pub fn top(mut self, top: impl Into<Top>) -> Self {
+        self.tops.push(top.into());
+        self
+    }
+
+    /// Add a free-floating `//` comment. Comments are printed before the blocks.
+    pub fn comment(mut self, text: impl Into<String>) -> Self {
+        self.comments.push(Comment {
+            text: text.into(),
+            is_doc: false,
+            span: Span::empty(),
+        });
+        self
+    }
+
+    /// Finish the schema.
+    pub fn build(self) -> SchemaAst {
+        SchemaAst {
+            tops: self.tops,
+            comments: self.comments,
+        }
+    }
+}
+
+impl SourceConfig {
+    /// Start building a datasource block with the given name.
+    pub fn builder(name: impl Into<String>) -> SourceConfigBuilder {
+        SourceConfigBuilder {
+            source: SourceConfig {
+                name: Identifier::new(name),
+                properties: Vec::new(),
+                documentation: None,
+                comments: Vec::new(),
+                span: Span::empty(),
+                inner_span: Span::empty(),
+            },
+        }
+    }
+}
+
+/// Builds a [`SourceConfig`]. Created with [`SourceConfig::builder()`].
+#[derive(Debug)]
+pub struct SourceConfigBuilder {
+    source: SourceConfig,
+}
+
+impl SourceConfigBuilder {
+    /// Add a `name = value` property.
+    pub fn property(mut self, name: impl Into<String>, value: Expression) -> Self {
+        self.source
+            .properties
+            .push(ConfigBlockProperty::new(name, value));
+        self
+    }
+
+    /// Set the doc comment above the block.
+    pub fn documentation(mut self, text: impl Into<String>) -> Self {
+        self.source.documentation = Some(doc_comment(text));
+        self
+    }
+
+    /// Finish the datasource block.
+    pub fn build(self) -> SourceConfig {
+        self.source
+    }
+}
+
+impl GeneratorConfig {
+    /// Start building a generator block with the given name.
+    pub fn builder(name: impl Into<String>) -> GeneratorConfigBuilder {
+        GeneratorConfigBuilder {
+            generator: GeneratorConfig {
+                name: Identifier::new(name),
+                properties: Vec::new(),
+                documentation: None,
+                comments: Vec::new(),
+                span: Span::empty(),
+                inner_span: Span::empty(),
+            },
+        }
+    }
+}
+
+/// Builds a [`GeneratorConfig`]. Created with [`GeneratorConfig::builder()`].
+#[derive(Debug)]
+pub struct GeneratorConfigBuilder {
+    generator: GeneratorConfig,
+}
+
+impl GeneratorConfigBuilder {
+    /// Add a `name = value` property.
+    pub fn property(mut self, name: impl Into<String>, value: Expression) -> Self {
+        self.generator
+            .properties
+            .push(ConfigBlockProperty::new(name, value));
+        self
+    }
+
+    /// Set the doc comment above the block.
+    pub fn documentation(mut self, text: impl Into<String>) -> Self {
+        self.generator.documentation = Some(doc_comment(text));
+        self
+    }
+
+    /// Finish the generator block.
+    pub fn build(self) -> GeneratorConfig {
+        self.generator
+    }
+}
+
+impl Model {
+    /// Start building a model with the given name.
+    pub fn builder(name: impl Into<String>) -> ModelBuilder {
+        ModelBuilder {
+            model: Model {
+                name: Identifier::new(name),
+                fields: Vec::new(),
+                attributes: Vec::new(),
+                documentation: None,
+                comments: Vec::new(),
+                span: Span::empty(),
+                inner_span: Span::empty(),
+            },
+        }
+    }
+}
+
+/// Builds a [`Model`]. Created with [`Model::builder()`].
+#[derive(Debug)]
+pub struct ModelBuilder {
+    model: Model,
+}
+
+impl ModelBuilder {
+    /// Add a field.
+    pub fn field(mut self, field: Field) -> Self {
+        self.model.fields.push(field);
+        self
+    }
+
+    /// Add a block attribute, like `@@map("users")`.
+    pub fn attribute(mut self, attribute: Attribute) -> Self {
+        self.model.attributes.push(attribute);
+        self
+    }
+
+    /// Set the doc comment above the model.
+    pub fn documentation(mut self, text: impl Into<String>) -> Self {
+        self.model.documentation = Some(doc_comment(text));
+        self
+    }
+
+    /// Finish the model.
+    pub fn build(self) -> Model {
+        self.model
+    }
+}
+
+impl CompositeType {
+    /// Start building a composite type with the given name.
+    pub fn builder(name: impl Into<String>) -> CompositeTypeBuilder {
+        CompositeTypeBuilder {
+            composite_type: CompositeType {
+                name: Identifier::new(name),
+                fields: Vec::new(),
+                documentation: None,
+                comments: Vec::new(),
+                span: Span::empty(),
+                inner_span: Span::empty(),
+            },
+        }
+    }
+}
+
+/// Builds a [`CompositeType`]. Created with [`CompositeType::builder()`].
+#[derive(Debug)]
+pub struct CompositeTypeBuilder {
+    composite_type: CompositeType,
+}
+
+impl CompositeTypeBuilder {
+    /// Add a field.
+    pub fn field(mut self, field: Field) -> Self {
+        self.composite_type.fields.push(field);
+        self
+    }
+
+    /// Set the doc comment above the composite type.
+    pub fn documentation(mut self, text: impl Into<String>) -> Self {
+        self.composite_type.documentation = Some(doc_comment(text));
+        self
+    }
+
+    /// Finish the composite type.
+    pub fn build(self) -> CompositeType {
+        self.composite_type
+    }
+}
+
+impl View {
+    /// Start building a view with the given name.
+    pub fn builder(name: impl Into<String>) -> ViewBuilder {
+        ViewBuilder {
+            view: View {
+                name: Identifier::new(name),
+                fields: Vec::new(),
+                attributes: Vec::new(),
+                documentation: None,
+                comments: Vec::new(),
+                span: Span::empty(),
+                inner_span: Span::empty(),
+            },
+        }
+    }
+}
+
+/// Builds a [`View`]. Created with [`View::builder()`].
+#[derive(Debug)]
+pub struct ViewBuilder {
+    view: View,
+}
+
+impl ViewBuilder {
+    /// Add a field.
+    pub fn field(mut self, field: Field) -> Self {
+        self.view.fields.push(field);
+        self
+    }
+
+    /// Add a block attribute, like `@@map("admins")`.
+    pub fn attribute(mut self, attribute: Attribute) -> Self {
+        self.view.attributes.push(attribute);
+        self
+    }
+
+    /// Set the doc comment above the view.
+    pub fn documentation(mut self, text: impl Into<String>) -> Self {
+        self.view.documentation = Some(doc_comment(text));
+        self
+    }
+
+    /// Finish the view.
+    pub fn build(self) -> View {
+        self.view
+    }
+}
+
+impl Field {
+    /// Start building a required field with the given name and type.
+    pub fn builder(name: impl Into<String>, field_type: impl Into<String>) -> FieldBuilder {
+        FieldBuilder {
+            field: Field {
+                field_type: FieldType::Supported(Identifier::new(field_type)),
+                name: Identifier::new(name),
+                arity: FieldArity::Required,
+                attributes: Vec::new(),
+                documentation: None,
+                trailing_comment: None,
+                span: Span::empty(),
+            },
+        }
+    }
+}
+
+/// Builds a [`Field`]. Created with [`Field::builder()`].
+#[derive(Debug)]
+pub struct FieldBuilder {
+    field: Field,
+}
+
+impl FieldBuilder {
+    /// Make the field optional or a list.
+    pub fn arity(mut self, arity: FieldArity) -> Self {
+        self.field.arity = arity;
+        self
+    }
+
+    /// Add a field attribute, like `@id`.
+    pub fn attribute(mut self, attribute: Attribute) -> Self {
+        self.field.attributes.push(attribute);
+        self
+    }
+
+    /// Set the doc comment above the field.
+    pub fn documentation(mut self, text: impl Into<String>) -> Self {
+        self.field.documentation = Some(doc_comment(text));
+        self
+    }
+
+    /// Finish the field.
+    pub fn build(self) -> Field {
+        self.field
+    }
+}
+
+impl Enum {
+    /// Start building an enum with the given name.
+    pub fn builder(name: impl Into<String>) -> EnumBuilder {
+        EnumBuilder {
+            r#enum: Enum {
+                name: Identifier::new(name),
+                values: Vec::new(),
+                attributes: Vec::new(),
+                documentation: None,
+                comments: Vec::new(),
+                span: Span::empty(),
+                inner_span: Span::empty(),
+            },
+        }
+    }
+}
+
+/// Builds an [`Enum`]. Created with [`Enum::builder()`].
+#[derive(Debug)]
+pub struct EnumBuilder {
+    r#enum: Enum,
+}
+
+impl EnumBuilder {
+    /// Add a value.
+    pub fn value(mut self, name: impl Into<String>) -> Self {
+        self.r#enum.values.push(EnumValue {
+            name: Identifier::new(name),
+            attributes: Vec::new(),
+            documentation: None,
+            trailing_comment: None,
+            span: Span::empty(),
+        });
+        self
+    }
+
+    /// Add a block attribute, like `@@map("roles")`.
+    pub fn attribute(mut self, attribute: Attribute) -> Self {
+        self.r#enum.attributes.push(attribute);
+        self
+    }
+
+    /// Set the doc comment above the enum.
+    pub fn documentation(mut self, text: impl Into<String>) -> Self {
+        self.r#enum.documentation = Some(doc_comment(text));
+        self
+    }
+
+    /// Finish the enum.
+    pub fn build(self) -> Enum {
+        self.r#enum
+    }
+}
+
+impl Identifier {
+    /// An identifier with an empty span.
+    pub fn new(name: impl Into<String>) -> Self {
+        Identifier {
+            name: name.into(),
+            span: Span::empty(),
+        }
+    }
+}
+
+impl Expression {
+    /// A string literal. It is escaped when printed.
+    pub fn string(value: impl Into<String>) -> Self {
+        Expression::StringValue(value.into(), Span::empty())
+    }
+
+    /// An integer literal.
+    pub fn number(value: impl NumericLiteral) -> Self {
+        Expression::NumericValue(value.to_string(), Span::empty())
+    }
+
+    /// A float literal, or `None` if the value is infinite or NaN: PSL has no
+    /// literal for them.
+    pub fn float(value: f64) -> Option<Self> {
+        value
+            .is_finite()
+            .then(|| Expression::NumericValue(value.to_string(), Span::empty()))
+    }
+
+    /// A constant, like `true`, `Cascade` or `db.VarChar`.
+    pub fn constant(value: impl Into<String>) -> Self {
+        Expression::ConstantValue(value.into(), Span::empty())
+    }
+
+    /// An array of values.
+    pub fn array(values: Vec<Expression>) -> Self {
+        Expression::Array(values, Span::empty())
+    }
+
+    /// A function call with unnamed arguments, like `env("DATABASE_URL")`.
+    pub fn function(name: impl Into<String>, arguments: Vec<Expression>) -> Self {
+        let arguments = ArgumentsList {
+            arguments: arguments.into_iter().map(Argument::unnamed).collect(),
+            ..Default::default()
+        };
+
+        Expression::Function(Identifier::new(name), arguments, Span::empty())
+    }
+}
+
+/// An integer that can be written as a PSL numeric literal, for
+/// [`Expression::number()`]. Floats go through [`Expression::float()`] instead.
+pub trait NumericLiteral: Copy + std::fmt::Display {}
+
+macro_rules! impl_numeric_literal {
+    ($($ty:ty),*) => {
+        $(impl NumericLiteral for $ty {})*
+    };
+}
+
+impl_numeric_literal!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Argument {
+    /// An unnamed argument.
+    pub fn unnamed(value: Expression) -> Self {
+        Argument {
+            name: None,
+            value,
+            span: Span::empty(),
+        }
+    }
+
+    /// A named argument, like `map: "users"`.
+    pub fn named(name: impl Into<String>, value: Expression) -> Self {
+        Argument {
+            name: Some(Identifier::new(name)),
+            value,
+            span: Span::empty(),
+        }
+    }
+}
+
+impl Attribute {
+    /// An attribute with the given name, without the `@` or `@@`.
+    pub fn new(name: impl Into<String>, arguments: Vec<Argument>) -> Self {
+        Attribute {
+            name: Identifier::new(name),
+            arguments: ArgumentsList {
+                arguments,
+                ..Default::default()
+            },
+            span: Span::empty(),
+        }
+    }
+}
+
+impl ConfigBlockProperty {
+    /// A `name = value` property.
+    pub fn new(name: impl Into<String>, value: Expression) -> Self {
+        ConfigBlockProperty {
+            name: Identifier::new(name),
+            value: Some(value),
+            trailing_comment: None,
+            documentation: None,
+            span: Span::empty(),
+        }
+    }
+}
+
+impl From<Model> for Top {
+    fn from(model: Model) -> Self {
+        Top::Model(model)
+    }
+}
+
+impl From<CompositeType> for Top {
+    fn from(composite_type: CompositeType) -> Self {
+        Top::CompositeType(composite_type)
+    }
+}
+
+impl From<View> for Top {
+    fn from(view: View) -> Self {
+        Top::View(view)
+    }
+}
+
+impl From<Enum> for Top {
+    fn from(r#enum: Enum) -> Self {
+        Top::Enum(r#enum)
+    }
+}
+
+impl From<GeneratorConfig> for Top {
+    fn from(generator: GeneratorConfig) -> Self {
+        Top::Generator(generator)
+    }
+}
+
+impl From<SourceConfig> for Top {
+    fn from(source: SourceConfig) -> Self {
+        Top::Source(source)
+    }
+}
+
+fn doc_comment(text: impl Into<String>) -> Comment {
+    Comment {
+        text: text.into(),
+        is_doc: true,
+        span: Span::empty(),
+    }
+}
@@ -1,4 +1,26 @@
+use super::{Span, WithSpan};
+
+/// A comment, either a `///` doc comment or a regular `//` one.
+///
+/// ```ignore
+/// /// Lorem ipsum
+///     ^^^^^^^^^^^
+/// ```
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) struct Comment {
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Comment {
+    /// The text of the comment, without the slashes. Consecutive doc comment
+    /// lines are joined with newlines.
     pub text: String,
+    /// Is this a `///` doc comment?
+    pub is_doc: bool,
+    /// The location of this comment in the text representation.
+    pub span: Span,
+}
+
+impl WithSpan for Comment {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
@@ -0,0 +1,96 @@
+use super::{
+    Comment, Field, FieldId, Identifier, Span, WithDocumentation, WithIdentifier, WithSpan,
+    WithSyntax,
+};
+use crate::cst::NodeKind;
+
+impl std::ops::Index<FieldId> for CompositeType {
+    type Output = Field;
+
+    fn index(&self, index: FieldId) -> &Self::Output {
+        &self.fields[index.0 as usize]
+    }
+}
+
+/// A type declaration in the data model. Defined by a type keyword and a block
+/// of fields that can have attributes.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct CompositeType {
+    /// The name of the type.
+    ///
+    /// ```ignore
+    /// type Foo { .. }
+    ///      ^^^
+    /// ```
+    pub name: Identifier,
+    /// The fields of the type.
+    ///
+    /// ```ignore
+    /// type Foo {
+    ///   bar String
+    ///   ^^^^^^^^^^
+    /// }
+    /// ```
+    pub fields: Vec<Field>,
+    /// The documentation for this type.
+    ///
+    /// ```ignore
+    /// /// Lorem ipsum
+    ///     ^^^^^^^^^^^
+    /// type Foo {
+    ///   bar String
+    /// }
+    /// ```
+    pub(crate) documentation: Option<Comment>,
+    /// The regular comments inside the block, in source order. Doc comments
+    /// above a member are its documentation instead.
+    ///
+    /// ```ignore
+    /// type Foo {
+    ///   // Lorem ipsum
+    ///      ^^^^^^^^^^^
+    ///   street String
+    /// }
+    /// ```
+    pub comments: Vec<Comment>,
+    /// The location of this type in the text representation.
+    pub span: Span,
+    /// The span of the inner contents.
+    pub inner_span: Span,
+}
+
+impl CompositeType {
+    /// Iterate over the fields of the type, along with their ids.
+    pub fn iter_fields(&self) -> impl ExactSizeIterator<Item = (FieldId, &Field)> + Clone {
+        self.fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| (FieldId(idx as u32), field))
+    }
+}
+
+impl WithIdentifier for CompositeType {
+    fn identifier(&self) -> &Identifier {
+        &self.name
+    }
+}
+
+impl WithSpan for CompositeType {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl WithSyntax for CompositeType {
+    fn syntax_kind(&self) -> NodeKind {
+        NodeKind::CompositeType
+    }
+}
+
+impl WithDocumentation for CompositeType {
+    fn documentation(&self) -> Option<&str> {
+        self.documentation.as_ref().map(|doc| doc.text.as_str())
+    }
+}
@@ -1,4 +1,5 @@
-use crate::ast::{Expression, Identifier, Span, WithSpan};
+use crate::ast::{Comment, Expression, Identifier, Span, WithDocumentation, WithSpan, WithSyntax};
+use crate::cst::NodeKind;
 
 /// A named property in a config block.
 ///
@@ -9,6 +10,8 @@ use crate::ast::{Expression, Identifier, Span, WithSpan};
 /// }
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ConfigBlockProperty {
     /// The property name.
     ///
@@ -28,6 +31,25 @@ pub struct ConfigBlockProperty {
     /// }
     /// ```
     pub value: Option<Expression>,
+    /// The comment at the end of the line, if any.
+    ///
+    /// ```ignore
+    /// datasource db {
+    ///     url = env("URL") // the connection string
+    ///                      ^^^^^^^^^^^^^^^^^^^^^^^^
+    /// }
+    /// ```
+    pub trailing_comment: Option<Comment>,
+    /// The doc comments above the property.
+    ///
+    /// ```ignore
+    /// datasource db {
+    ///     /// Lorem ipsum
+    ///         ^^^^^^^^^^^
+    ///     url = env("URL")
+    /// }
+    /// ```
+    pub(crate) documentation: Option<Comment>,
     /// The node span.
     pub span: Span,
 }
@@ -37,3 +59,15 @@ impl WithSpan for ConfigBlockProperty {
         self.span
     }
 }
+
+impl WithSyntax for ConfigBlockProperty {
+    fn syntax_kind(&self) -> NodeKind {
+        NodeKind::Property
+    }
+}
+
+impl WithDocumentation for ConfigBlockProperty {
+    fn documentation(&self) -> Option<&str> {
+        self.documentation.as_ref().map(|doc| doc.text.as_str())
+    }
+}
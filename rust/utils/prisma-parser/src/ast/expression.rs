@@ -4,29 +4,98 @@ use std::fmt;
 /// Represents arbitrary, even nested, expressions.
 #[derive(Debug, Clone)]
 pub enum Expression {
+    /// Any numeric value e.g. floats or ints.
+    NumericValue(String, Span),
     /// Any string value.
     StringValue(String, Span),
+    /// Any literal constant, basically a string which was not inside "...".
+    /// This is used for representing builtin enums and paths like `db.VarChar`.
+    ConstantValue(String, Span),
     /// A function call like node with a name and arguments.
-    Function(String, ast::ArgumentsList, Span),
+    ///
+    /// ```ignore
+    /// url = env("DATABASE_URL")
+    ///       ^^^^^^^^^^^^^^^^^^^
+    /// ```
+    Function(ast::Identifier, ast::ArgumentsList, Span),
+    /// An array of other values.
+    Array(Vec<Expression>, Span),
 }
 
 impl fmt::Display for Expression {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Expression::NumericValue(val, _) => fmt::Display::fmt(val, f),
             Expression::StringValue(val, _) => write!(f, "{}", crate::string_literal(val)),
+            Expression::ConstantValue(val, _) => fmt::Display::fmt(val, f),
             Expression::Function(fun, args, _) => {
                 let args = args
                     .iter()
                     .map(ToString::to_string)
                     .collect::<Vec<_>>()
                     .join(",");
-                write!(f, "{fun}({args})")
+                write!(f, "{}({args})", fun.name)
+            }
+            Expression::Array(vals, _) => {
+                let vals = vals
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",");
+                write!(f, "[{vals}]")
             }
         }
     }
 }
 
+/// Serialized as an object tagged with its `type`, e.g.
+/// `{ "type": "stringValue", "value": "postgres", "span": { .. } }`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Expression {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Expression", 4)?;
+        match self {
+            Expression::NumericValue(value, span) => {
+                state.serialize_field("type", "numericValue")?;
+                state.serialize_field("value", value)?;
+                state.serialize_field("span", span)?;
+            }
+            Expression::StringValue(value, span) => {
+                state.serialize_field("type", "stringValue")?;
+                state.serialize_field("value", value)?;
+                state.serialize_field("span", span)?;
+            }
+            Expression::ConstantValue(value, span) => {
+                state.serialize_field("type", "constantValue")?;
+                state.serialize_field("value", value)?;
+                state.serialize_field("span", span)?;
+            }
+            Expression::Function(name, arguments, span) => {
+                state.serialize_field("type", "function")?;
+                state.serialize_field("name", name)?;
+                state.serialize_field("arguments", arguments)?;
+                state.serialize_field("span", span)?;
+            }
+            Expression::Array(values, span) => {
+                state.serialize_field("type", "array")?;
+                state.serialize_field("values", values)?;
+                state.serialize_field("span", span)?;
+            }
+        }
+        state.end()
+    }
+}
+
 impl Expression {
+    pub fn as_array(&self) -> Option<(&[Expression], Span)> {
+        match self {
+            Expression::Array(arr, span) => Some((arr, *span)),
+            _ => None,
+        }
+    }
+
     pub fn as_string_value(&self) -> Option<(&str, Span)> {
         match self {
             Expression::StringValue(s, span) => Some((s, *span)),
@@ -34,23 +103,40 @@ impl Expression {
         }
     }
 
+    pub fn as_constant_value(&self) -> Option<(&str, Span)> {
+        match self {
+            Expression::ConstantValue(s, span) => Some((s, *span)),
+            _ => None,
+        }
+    }
+
+    pub fn as_numeric_value(&self) -> Option<(&str, Span)> {
+        match self {
+            Expression::NumericValue(s, span) => Some((s, *span)),
+            _ => None,
+        }
+    }
+
     pub fn as_function(&self) -> Option<(&str, &ast::ArgumentsList, Span)> {
         match self {
-            Expression::Function(name, args, span) => Some((name, args, *span)),
+            Expression::Function(name, args, span) => Some((&name.name, args, *span)),
             _ => None,
         }
     }
 
     pub fn span(&self) -> Span {
         match &self {
+            Self::NumericValue(_, span) => *span,
             Self::StringValue(_, span) => *span,
+            Self::ConstantValue(_, span) => *span,
             Self::Function(_, _, span) => *span,
+            Self::Array(_, span) => *span,
         }
     }
 
     pub fn is_env_expression(&self) -> bool {
         match &self {
-            Self::Function(name, _, _) => name == "env",
+            Self::Function(name, _, _) => name.name == "env",
             _ => false,
         }
     }
@@ -58,8 +144,11 @@ impl Expression {
     /// Creates a friendly readable representation for a value's type.
     pub fn describe_value_type(&self) -> &'static str {
         match self {
+            Expression::NumericValue(_, _) => "numeric",
             Expression::StringValue(_, _) => "string",
+            Expression::ConstantValue(_, _) => "literal",
             Expression::Function(_, _, _) => "functional",
+            Expression::Array(_, _) => "array",
         }
     }
 
@@ -67,7 +156,67 @@ impl Expression {
         matches!(self, Expression::Function(_, _, _))
     }
 
+    pub fn is_array(&self) -> bool {
+        matches!(self, Expression::Array(_, _))
+    }
+
     pub fn is_string(&self) -> bool {
         matches!(self, Expression::StringValue(_, _))
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::{diagnostics::Diagnostics, parse_schema};
+    use serde_json::json;
+
+    #[test]
+    fn serialize_schema_test() {
+        let schema = "datasource db {\n  url = env(\"URL\") // secret\n}\n";
+        let ast = parse_schema(schema, &mut Diagnostics::new());
+        let json = serde_json::to_value(&ast).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "tops": [{
+                    "type": "source",
+                    "name": { "name": "db", "span": { "start": 11, "end": 13, "fileId": 0 } },
+                    "properties": [{
+                        "name": { "name": "url", "span": { "start": 18, "end": 21, "fileId": 0 } },
+                        "value": {
+                            "type": "function",
+                            "name": { "name": "env", "span": { "start": 24, "end": 27, "fileId": 0 } },
+                            "arguments": {
+                                "arguments": [{
+                                    "name": null,
+                                    "value": {
+                                        "type": "stringValue",
+                                        "value": "URL",
+                                        "span": { "start": 28, "end": 33, "fileId": 0 },
+                                    },
+                                    "span": { "start": 28, "end": 33, "fileId": 0 },
+                                }],
+                                "emptyArguments": [],
+                                "trailingComma": null,
+                            },
+                            "span": { "start": 24, "end": 34, "fileId": 0 },
+                        },
+                        "trailingComment": {
+                            "text": "secret",
+                            "isDoc": false,
+                            "span": { "start": 35, "end": 44, "fileId": 0 },
+                        },
+                        "documentation": null,
+                        "span": { "start": 18, "end": 44, "fileId": 0 },
+                    }],
+                    "documentation": null,
+                    "comments": [],
+                    "span": { "start": 0, "end": 46, "fileId": 0 },
+                    "innerSpan": { "start": 18, "end": 45, "fileId": 0 },
+                }],
+                "comments": [],
+            })
+        );
+    }
+}
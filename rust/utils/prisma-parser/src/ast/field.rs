@@ -0,0 +1,200 @@
+use super::{
+    Attribute, Comment, Identifier, Span, WithAttributes, WithDocumentation, WithIdentifier,
+    WithSpan, WithSyntax,
+};
+use crate::cst::NodeKind;
+
+/// A field definition in a model.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Field {
+    /// The field's type.
+    ///
+    /// ```ignore
+    /// name String
+    ///      ^^^^^^
+    /// ```
+    pub field_type: FieldType,
+    /// The name of the field.
+    ///
+    /// ```ignore
+    /// name String
+    /// ^^^^
+    /// ```
+    pub name: Identifier,
+    /// The arity of the field.
+    pub arity: FieldArity,
+    /// The attributes of this field.
+    ///
+    /// ```ignore
+    /// id Int @id @default(autoincrement())
+    ///        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    pub attributes: Vec<Attribute>,
+    /// The comments for this field.
+    ///
+    /// ```ignore
+    /// /// Lorem ipsum
+    ///     ^^^^^^^^^^^
+    /// name String
+    /// ```
+    pub(crate) documentation: Option<Comment>,
+    /// The regular comment at the end of the line, if any. A trailing doc
+    /// comment is part of the documentation instead.
+    ///
+    /// ```ignore
+    /// name String // Lorem ipsum
+    ///             ^^^^^^^^^^^^^^
+    /// ```
+    pub trailing_comment: Option<Comment>,
+    /// The location of this field in the text representation.
+    pub span: Span,
+}
+
+impl Field {
+    /// Is the field optional (`Type?`)?
+    pub fn is_optional(&self) -> bool {
+        self.arity.is_optional()
+    }
+
+    /// Is the field a list (`Type[]`)?
+    pub fn is_list(&self) -> bool {
+        self.arity.is_list()
+    }
+}
+
+impl WithIdentifier for Field {
+    fn identifier(&self) -> &Identifier {
+        &self.name
+    }
+}
+
+impl WithAttributes for Field {
+    fn attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+}
+
+impl WithSpan for Field {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl WithSyntax for Field {
+    fn syntax_kind(&self) -> NodeKind {
+        NodeKind::Field
+    }
+}
+
+impl WithDocumentation for Field {
+    fn documentation(&self) -> Option<&str> {
+        self.documentation.as_ref().map(|doc| doc.text.as_str())
+    }
+}
+
+/// An arity of a data model field.
+#[derive(Copy, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum FieldArity {
+    /// The field either must be in an insert statement, or the field must have
+    /// a default value for the insert to succeed.
+    ///
+    /// ```ignore
+    /// name String
+    /// ```
+    Required,
+    /// The field does not need to be in an insert statement for the write to
+    /// succeed.
+    ///
+    /// ```ignore
+    /// name String?
+    /// ```
+    Optional,
+    /// The field can have multiple values stored in the same column.
+    ///
+    /// ```ignore
+    /// name String[]
+    /// ```
+    List,
+}
+
+impl FieldArity {
+    pub fn is_list(&self) -> bool {
+        matches!(self, &FieldArity::List)
+    }
+
+    pub fn is_optional(&self) -> bool {
+        matches!(self, &FieldArity::Optional)
+    }
+
+    pub fn is_required(&self) -> bool {
+        matches!(self, &FieldArity::Required)
+    }
+}
+
+/// The type of a field.
+#[derive(Debug, Clone)]
+pub enum FieldType {
+    /// A type referring to a scalar, a model, an enum or a composite type.
+    ///
+    /// ```ignore
+    /// name String
+    ///      ^^^^^^
+    /// ```
+    Supported(Identifier),
+    /// Unsupported("...")
+    ///
+    /// ```ignore
+    /// location Unsupported("point")
+    ///          ^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    Unsupported(String, Span),
+}
+
+/// Serialized as `{ "type": "supported" | "unsupported", "name": .., "span": .. }`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for FieldType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let (kind, name, span) = match self {
+            FieldType::Supported(ident) => ("supported", &ident.name, &ident.span),
+            FieldType::Unsupported(name, span) => ("unsupported", name, span),
+        };
+
+        let mut state = serializer.serialize_struct("FieldType", 3)?;
+        state.serialize_field("type", kind)?;
+        state.serialize_field("name", name)?;
+        state.serialize_field("span", span)?;
+        state.end()
+    }
+}
+
+impl FieldType {
+    /// The location of the type in the text representation.
+    pub fn span(&self) -> Span {
+        match self {
+            FieldType::Supported(ident) => ident.span,
+            FieldType::Unsupported(_, span) => *span,
+        }
+    }
+
+    /// The type name, if the type is supported.
+    pub fn as_supported(&self) -> Option<&Identifier> {
+        match self {
+            FieldType::Supported(ident) => Some(ident),
+            FieldType::Unsupported(_, _) => None,
+        }
+    }
+
+    /// The type description, if the type is `Unsupported("...")`.
+    pub fn as_unsupported(&self) -> Option<(&str, &Span)> {
+        match self {
+            FieldType::Unsupported(name, span) => Some((name, span)),
+            FieldType::Supported(_) => None,
+        }
+    }
+}
@@ -1,31 +1,78 @@
 use crate::ast::{self, top_idx_to_top_id, traits::*};
 
 impl ast::SchemaAst {
-    /// Find the AST node at the given position (byte offset).
+    /// Find the AST node at the given position (byte offset) in a single-file schema.
     pub fn find_at_position(&self, position: usize) -> SchemaPosition<'_> {
-        self.find_top_at_position(position)
+        self.find_at_position_in_file(ast::FileId::ZERO, position)
+    }
+
+    /// Find the AST node at the given position (byte offset) in a file of a
+    /// multi-file schema.
+    pub fn find_at_position_in_file(
+        &self,
+        file_id: ast::FileId,
+        position: usize,
+    ) -> SchemaPosition<'_> {
+        self.find_top_at_position_in_file(file_id, position)
             .map(|top_id| match top_id {
+                ast::TopId::Model(model_id) => SchemaPosition::Model(
+                    model_id,
+                    ModelPosition::new(
+                        self[model_id].iter_fields(),
+                        &self[model_id].attributes,
+                        position,
+                    ),
+                ),
+                ast::TopId::CompositeType(composite_type_id) => SchemaPosition::CompositeType(
+                    composite_type_id,
+                    ModelPosition::new(self[composite_type_id].iter_fields(), &[], position),
+                ),
+                ast::TopId::View(view_id) => SchemaPosition::View(
+                    view_id,
+                    ModelPosition::new(
+                        self[view_id].iter_fields(),
+                        &self[view_id].attributes,
+                        position,
+                    ),
+                ),
+                ast::TopId::Enum(enum_id) => {
+                    SchemaPosition::Enum(enum_id, EnumPosition::new(&self[enum_id], position))
+                }
+                ast::TopId::Generator(generator_id) => SchemaPosition::Generator(
+                    generator_id,
+                    GeneratorPosition::new(&self[generator_id], position),
+                ),
                 ast::TopId::Source(source_id) => SchemaPosition::DataSource(
                     source_id,
                     SourcePosition::new(&self[source_id], position),
                 ),
-                // Falling back to TopLevel as "not implemented"
-                _ => SchemaPosition::TopLevel,
+                ast::TopId::Invalid(invalid_block_id) => SchemaPosition::Invalid(invalid_block_id),
             })
             // If no top matched, we're in between top-level items. This is normal and expected.
             .unwrap_or(SchemaPosition::TopLevel)
     }
 
-    /// Do a binary search for the `Top` at the given byte offset.
+    /// Do a binary search for the `Top` at the given byte offset in a single-file
+    /// schema.
     pub fn find_top_at_position(&self, position: usize) -> Option<ast::TopId> {
+        self.find_top_at_position_in_file(ast::FileId::ZERO, position)
+    }
+
+    /// Do a binary search for the `Top` at the given byte offset in a file. The
+    /// tops are ordered by file, then by offset.
+    pub fn find_top_at_position_in_file(
+        &self,
+        file_id: ast::FileId,
+        position: usize,
+    ) -> Option<ast::TopId> {
         use std::cmp::Ordering;
 
         let top_idx = self.tops.binary_search_by(|top| {
             let span = top.span();
 
-            if span.start > position {
+            if (span.file_id, span.start) > (file_id, position) {
                 Ordering::Greater
-            } else if span.end < position {
+            } else if (span.file_id, span.end) < (file_id, position) {
                 Ordering::Less
             } else {
                 Ordering::Equal
@@ -43,65 +90,281 @@ impl ast::SchemaAst {
 pub enum SchemaPosition<'ast> {
     /// In-between top-level items
     TopLevel,
+    /// In a model
+    Model(ast::ModelId, ModelPosition<'ast>),
+    /// In a composite type
+    CompositeType(ast::CompositeTypeId, ModelPosition<'ast>),
+    /// In a view
+    View(ast::ViewId, ModelPosition<'ast>),
+    /// In an enum
+    Enum(ast::EnumId, EnumPosition<'ast>),
+    /// In a generator
+    Generator(ast::GeneratorId, GeneratorPosition<'ast>),
     /// In a datasource
     DataSource(ast::SourceId, SourcePosition<'ast>),
+    /// In a block that could not be parsed
+    Invalid(ast::InvalidBlockId),
+}
+
+/// A cursor position in a model, composite type or view. They share the same syntax.
+#[derive(Debug)]
+pub enum ModelPosition<'ast> {
+    /// In the block, but not somewhere more specific.
+    Model,
+    /// In a block attribute (attr name, attr index, argument name).
+    ModelAttribute(&'ast str, usize, Option<&'ast str>),
+    /// In a field.
+    Field(ast::FieldId, FieldPosition<'ast>),
+}
+
+impl<'ast> ModelPosition<'ast> {
+    fn new(
+        fields: impl Iterator<Item = (ast::FieldId, &'ast ast::Field)>,
+        attributes: &'ast [ast::Attribute],
+        position: usize,
+    ) -> Self {
+        for (field_id, field) in fields {
+            if field.span().contains(position) {
+                return ModelPosition::Field(field_id, FieldPosition::new(field, position));
+            }
+        }
+
+        for (attr_idx, attr) in attributes.iter().enumerate() {
+            if attr.span().contains(position) {
+                return ModelPosition::ModelAttribute(
+                    attr.name(),
+                    attr_idx,
+                    argument_name_at_position(&attr.arguments, position),
+                );
+            }
+        }
+
+        ModelPosition::Model
+    }
+}
+
+/// A cursor position in a field.
+#[derive(Debug)]
+pub enum FieldPosition<'ast> {
+    /// Nowhere specific inside the field
+    Field,
+    /// In the field type (type name).
+    Type(&'ast str),
+    /// In an attribute (attr name, attr index, argument name).
+    Attribute(&'ast str, usize, Option<&'ast str>),
+}
+
+impl<'ast> FieldPosition<'ast> {
+    fn new(field: &'ast ast::Field, position: usize) -> FieldPosition<'ast> {
+        if field.field_type.span().contains(position) {
+            let name = match &field.field_type {
+                ast::FieldType::Supported(ident) => ident.name.as_str(),
+                ast::FieldType::Unsupported(name, _) => name.as_str(),
+            };
+
+            return FieldPosition::Type(name);
+        }
+
+        for (attr_idx, attr) in field.attributes.iter().enumerate() {
+            if attr.span().contains(position) {
+                return FieldPosition::Attribute(
+                    attr.name(),
+                    attr_idx,
+                    argument_name_at_position(&attr.arguments, position),
+                );
+            }
+        }
+
+        FieldPosition::Field
+    }
+}
+
+/// A cursor position in an enum.
+#[derive(Debug)]
+pub enum EnumPosition<'ast> {
+    /// In the enum, but not somewhere more specific.
+    Enum,
+    /// In a block attribute (attr name, attr index, argument name).
+    EnumAttribute(&'ast str, usize, Option<&'ast str>),
+    /// In a value.
+    Value(&'ast str, EnumValuePosition<'ast>),
+}
+
+impl<'ast> EnumPosition<'ast> {
+    fn new(r#enum: &'ast ast::Enum, position: usize) -> Self {
+        for (enum_value_id, value) in r#enum.iter_values() {
+            if value.span().contains(position) {
+                return EnumPosition::Value(
+                    value.name(),
+                    EnumValuePosition::new(&r#enum[enum_value_id], position),
+                );
+            }
+        }
+
+        for (attr_idx, attr) in r#enum.attributes.iter().enumerate() {
+            if attr.span().contains(position) {
+                return EnumPosition::EnumAttribute(
+                    attr.name(),
+                    attr_idx,
+                    argument_name_at_position(&attr.arguments, position),
+                );
+            }
+        }
+
+        EnumPosition::Enum
+    }
+}
+
+/// A cursor position in an enum value.
+#[derive(Debug)]
+pub enum EnumValuePosition<'ast> {
+    /// Nowhere specific inside the value
+    Value,
+    /// In an attribute (attr name, attr index, argument name).
+    Attribute(&'ast str, usize, Option<&'ast str>),
+}
+
+impl<'ast> EnumValuePosition<'ast> {
+    fn new(value: &'ast ast::EnumValue, position: usize) -> EnumValuePosition<'ast> {
+        for (attr_idx, attr) in value.attributes.iter().enumerate() {
+            if attr.span().contains(position) {
+                return EnumValuePosition::Attribute(
+                    attr.name(),
+                    attr_idx,
+                    argument_name_at_position(&attr.arguments, position),
+                );
+            }
+        }
+
+        EnumValuePosition::Value
+    }
 }
 
+/// A cursor position in an expression, e.g. the value of a datasource property.
 #[derive(Debug)]
 pub enum ExpressionPosition<'ast> {
+    /// In the expression, but not somewhere more specific.
     Expression,
+    /// In a number or a constant.
     Value(&'ast str),
+    /// Between the quotes of a string literal.
+    StringValue(&'ast str),
+    /// On the name of a function.
     Function(&'ast str),
-    FunctionArgument(&'ast str, &'ast str),
+    /// In the parentheses of a function call (function name, argument index, argument name,
+    /// position in the argument). Between arguments, the index is the one of the next argument.
+    /// The name is found like for attribute arguments.
+    FunctionArgument(
+        &'ast str,
+        usize,
+        Option<&'ast str>,
+        Box<ExpressionPosition<'ast>>,
+    ),
+    /// Between the trailing comma and the closing parenthesis of a function call (function
+    /// name).
+    AfterTrailingComma(&'ast str),
 }
 
 impl<'ast> ExpressionPosition<'ast> {
     fn new(expr: &'ast ast::Expression, position: usize) -> Self {
         match expr {
-            ast::Expression::StringValue(val, span) if span.contains(position) => Self::Value(val),
+            ast::Expression::NumericValue(val, span)
+            | ast::Expression::ConstantValue(val, span)
+                if span.contains(position) =>
+            {
+                Self::Value(val)
+            }
+            // The span includes the quotes.
+            ast::Expression::StringValue(val, span)
+                if position > span.start && position < span.end =>
+            {
+                Self::StringValue(val)
+            }
+            ast::Expression::Array(exprs, span) if span.contains(position) => exprs
+                .iter()
+                .map(|expr| ExpressionPosition::new(expr, position))
+                .find(|position| !matches!(position, ExpressionPosition::Expression))
+                .unwrap_or(Self::Expression),
             ast::Expression::Function(name, args, span) if span.contains(position) => {
-                let mut spans: Vec<(Option<&str>, ast::Span)> = args
-                    .arguments
-                    .iter()
-                    .map(|arg| (arg.name.as_ref().map(|n| n.name.as_str()), arg.span()))
-                    .chain(
-                        args.empty_arguments
-                            .iter()
-                            .map(|arg| (Some(arg.name.name.as_str()), arg.name.span())),
-                    )
-                    .collect();
-
-                spans.sort_by_key(|(_, span)| span.start);
-
-                let mut arg_name = None;
-                for (name, _) in spans.iter().take_while(|(_, span)| span.start < position) {
-                    arg_name = Some(*name);
+                if name.span.contains(position) {
+                    return Self::Function(&name.name);
                 }
 
-                // If the cursor is after a trailing comma, we're not in an argument.
-                if let Some(span) = args.trailing_comma {
-                    if position > span.start {
-                        arg_name = None;
+                // The span ends after the closing parenthesis.
+                if position == span.end {
+                    return Self::Expression;
+                }
+
+                if let Some(comma) = args.trailing_comma {
+                    if position >= comma.end {
+                        return Self::AfterTrailingComma(&name.name);
                     }
                 }
 
-                if let Some(arg_name) = arg_name.flatten() {
-                    Self::FunctionArgument(name, arg_name)
-                } else {
-                    Self::Function(name)
+                let arg_name = argument_name_at_position(args, position);
+
+                for (idx, arg) in args.arguments.iter().enumerate() {
+                    if arg.value.span().contains(position) {
+                        let inner = ExpressionPosition::new(&arg.value, position);
+                        return Self::FunctionArgument(&name.name, idx, arg_name, Box::new(inner));
+                    }
                 }
+
+                let idx = args
+                    .arguments
+                    .partition_point(|arg| arg.span.end < position);
+
+                Self::FunctionArgument(&name.name, idx, arg_name, Box::new(Self::Expression))
             }
             _ => Self::Expression,
         }
     }
 }
 
+/// The name of the argument the cursor is in, for attribute and function arguments.
+///
+/// We can't go by `Span::contains()` because we also care about the empty space
+/// between arguments and that's hard to capture in the pest grammar.
+fn argument_name_at_position(args: &ast::ArgumentsList, position: usize) -> Option<&str> {
+    let mut spans: Vec<(Option<&str>, ast::Span)> = args
+        .arguments
+        .iter()
+        .map(|arg| (arg.name.as_ref().map(|n| n.name.as_str()), arg.span()))
+        .chain(
+            args.empty_arguments
+                .iter()
+                .map(|arg| (Some(arg.name.name.as_str()), arg.name.span())),
+        )
+        .collect();
+
+    spans.sort_by_key(|(_, span)| span.start);
+
+    let mut arg_name = None;
+    for (name, _) in spans.iter().take_while(|(_, span)| span.start < position) {
+        arg_name = Some(*name);
+    }
+
+    // If the cursor is after a trailing comma, we're not in an argument.
+    if let Some(span) = args.trailing_comma {
+        if position > span.start {
+            arg_name = None;
+        }
+    }
+
+    arg_name.flatten()
+}
+
+/// A cursor position in a datasource.
 #[derive(Debug)]
 pub enum SourcePosition<'ast> {
     /// In the general datasource
     Source,
     /// In a property
     Property(&'ast str, PropertyPosition<'ast>),
+    /// On the `datasource` keyword
+    Keyword,
+    /// On the name of the datasource
+    Name(&'ast str),
     /// Outside of the braces
     Outer,
 }
@@ -121,35 +384,83 @@ impl<'ast> SourcePosition<'ast> {
             return SourcePosition::Source;
         }
 
+        if source.name.span.contains(position) {
+            return SourcePosition::Name(&source.name.name);
+        }
+
+        if position < source.span.start + "datasource".len() {
+            return SourcePosition::Keyword;
+        }
+
         SourcePosition::Outer
     }
 }
 
+/// A cursor position in a generator.
+#[derive(Debug)]
+pub enum GeneratorPosition<'ast> {
+    /// In the general generator
+    Generator,
+    /// In a property
+    Property(&'ast str, PropertyPosition<'ast>),
+    /// On the `generator` keyword
+    Keyword,
+    /// On the name of the generator
+    Name(&'ast str),
+    /// Outside of the braces
+    Outer,
+}
+
+impl<'ast> GeneratorPosition<'ast> {
+    fn new(generator: &'ast ast::GeneratorConfig, position: usize) -> Self {
+        for property in &generator.properties {
+            if property.span.contains(position) {
+                return GeneratorPosition::Property(
+                    &property.name.name,
+                    PropertyPosition::new(property, position),
+                );
+            }
+        }
+
+        if generator.inner_span.contains(position) {
+            return GeneratorPosition::Generator;
+        }
+
+        if generator.name.span.contains(position) {
+            return GeneratorPosition::Name(&generator.name.name);
+        }
+
+        if position < generator.span.start + "generator".len() {
+            return GeneratorPosition::Keyword;
+        }
+
+        GeneratorPosition::Outer
+    }
+}
+
+/// A cursor position in a datasource or generator property.
 #[derive(Debug)]
 pub enum PropertyPosition<'ast> {
-    /// prop
+    /// On the property name, or in its trailing comment
     Property,
-    ///
-    Value(&'ast str),
-    ///
-    FunctionValue(&'ast str),
+    /// After the `=`, in the value or where it is missing
+    Value(ExpressionPosition<'ast>),
 }
 
 impl<'ast> PropertyPosition<'ast> {
     fn new(property: &'ast ast::ConfigBlockProperty, position: usize) -> Self {
-        if let Some(val) = &property.value {
-            if val.span().contains(position) && val.is_function() {
-                let func = val.as_function().unwrap();
+        let in_comment = property
+            .trailing_comment
+            .as_ref()
+            .is_some_and(|comment| comment.span.contains(position));
 
-                if func.0 == "env" {
-                    return PropertyPosition::FunctionValue("env");
-                }
-            }
-        }
-        if property.span.contains(position) && !property.name.span.contains(position) {
-            return PropertyPosition::Value(&property.name.name);
+        if property.name.span.contains(position) || in_comment {
+            return PropertyPosition::Property;
         }
 
-        PropertyPosition::Property
+        match &property.value {
+            Some(value) => PropertyPosition::Value(ExpressionPosition::new(value, position)),
+            None => PropertyPosition::Value(ExpressionPosition::Expression),
+        }
     }
 }
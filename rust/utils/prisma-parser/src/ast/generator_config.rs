@@ -0,0 +1,49 @@
+use super::{
+    Comment, ConfigBlockProperty, Identifier, Span, WithDocumentation, WithIdentifier, WithSpan,
+    WithSyntax,
+};
+use crate::cst::NodeKind;
+
+/// A generator block declaration.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct GeneratorConfig {
+    /// Name of this generator.
+    pub name: Identifier,
+    /// Top-level configuration properties for this generator.
+    pub properties: Vec<ConfigBlockProperty>,
+    /// The comments for this generator block.
+    pub(crate) documentation: Option<Comment>,
+    /// The regular comments inside the block, in source order. Doc comments
+    /// above a property are its documentation instead.
+    pub comments: Vec<Comment>,
+    /// The location of this generator block in the text representation.
+    pub span: Span,
+    /// The span of the inner contents.
+    pub inner_span: Span,
+}
+
+impl WithIdentifier for GeneratorConfig {
+    fn identifier(&self) -> &Identifier {
+        &self.name
+    }
+}
+
+impl WithSpan for GeneratorConfig {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl WithSyntax for GeneratorConfig {
+    fn syntax_kind(&self) -> NodeKind {
+        NodeKind::Generator
+    }
+}
+
+impl WithDocumentation for GeneratorConfig {
+    fn documentation(&self) -> Option<&str> {
+        self.documentation.as_ref().map(|doc| doc.text.as_str())
+    }
+}
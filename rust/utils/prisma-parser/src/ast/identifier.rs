@@ -2,6 +2,8 @@ use super::{Span, WithSpan};
 
 /// An identifier.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct Identifier {
     /// The identifier contents.
     pub name: String,
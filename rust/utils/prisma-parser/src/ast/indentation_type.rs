@@ -9,6 +9,44 @@ pub enum IndentationType {
     Spaces(usize),
 }
 
+impl IndentationType {
+    /// Detect the dominant indentation of a schema: tabs, or the most common
+    /// number of leading spaces. Schemas without indented lines get the default.
+    pub fn detect(schema: &str) -> Self {
+        let mut tabs = 0;
+        let mut spaces: Vec<(usize, usize)> = Vec::new();
+
+        for line in schema.lines().filter(|line| !line.trim().is_empty()) {
+            if line.starts_with('\t') {
+                tabs += 1;
+                continue;
+            }
+
+            let width = line.len() - line.trim_start_matches(' ').len();
+
+            if width == 0 {
+                continue;
+            }
+
+            match spaces.iter_mut().find(|(w, _)| *w == width) {
+                Some((_, count)) => *count += 1,
+                None => spaces.push((width, 1)),
+            }
+        }
+
+        // The most common width wins, the narrowest one on ties.
+        let dominant = spaces
+            .into_iter()
+            .max_by(|(w1, c1), (w2, c2)| c1.cmp(c2).then(w2.cmp(w1)));
+
+        match dominant {
+            Some((width, count)) if count >= tabs => Self::Spaces(width),
+            _ if tabs > 0 => Self::Tabs,
+            _ => Self::default(),
+        }
+    }
+}
+
 impl Default for IndentationType {
     /// Prisma defaults to the JavaScript default of two spaces.
     fn default() -> Self {
@@ -30,3 +68,21 @@ impl fmt::Display for IndentationType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_indentation_test() {
+        let four = "model A {\n    id Int\n    name String\n      // deeper\n}\n";
+        let tabs = "model A {\n\tid Int\n\tname String\n}\n";
+
+        assert_eq!(IndentationType::detect(four), IndentationType::Spaces(4));
+        assert_eq!(IndentationType::detect(tabs), IndentationType::Tabs);
+        assert_eq!(
+            IndentationType::detect("model A {\n}\n"),
+            IndentationType::default()
+        );
+    }
+}
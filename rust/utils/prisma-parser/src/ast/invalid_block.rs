@@ -0,0 +1,40 @@
+use super::{Identifier, Span, WithSpan, WithSyntax};
+use crate::cst::NodeKind;
+
+/// A top-level block that could not be parsed. The parser keeps it in the
+/// AST so that the rest of the schema can still be parsed and inspected.
+///
+/// ```ignore
+/// model {
+/// ^^^^^^^
+///   id Int @id
+/// ^^^^^^^^^^^^
+/// }
+/// ^
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct InvalidBlock {
+    /// The name of the block, if the parser could find one.
+    ///
+    /// ```ignore
+    /// model Foo {}
+    ///       ^^^
+    /// ```
+    pub name: Option<Identifier>,
+    /// The location of this block in the text representation.
+    pub span: Span,
+}
+
+impl WithSpan for InvalidBlock {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl WithSyntax for InvalidBlock {
+    fn syntax_kind(&self) -> NodeKind {
+        NodeKind::InvalidBlock
+    }
+}
@@ -0,0 +1,123 @@
+use super::{
+    Attribute, Comment, Field, Identifier, Span, WithAttributes, WithDocumentation,
+    WithIdentifier, WithSpan, WithSyntax,
+};
+use crate::cst::NodeKind;
+
+/// An opaque identifier for a field in an AST model. Use the
+/// `model[field_id]` syntax to resolve the id to an `ast::Field`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FieldId(pub(super) u32);
+
+impl std::ops::Index<FieldId> for Model {
+    type Output = Field;
+
+    fn index(&self, index: FieldId) -> &Self::Output {
+        &self.fields[index.0 as usize]
+    }
+}
+
+/// A model declaration.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Model {
+    /// The name of the model.
+    ///
+    /// ```ignore
+    /// model Foo { .. }
+    ///       ^^^
+    /// ```
+    pub name: Identifier,
+    /// The fields of the model.
+    ///
+    /// ```ignore
+    /// model Foo {
+    ///   id    Int    @id
+    ///   ^^^^^^^^^^^^^^^^
+    ///   field String
+    ///   ^^^^^^^^^^^^
+    /// }
+    /// ```
+    pub fields: Vec<Field>,
+    /// The attributes of this model.
+    ///
+    /// ```ignore
+    /// model Foo {
+    ///   id    Int    @id
+    ///   field String
+    ///
+    ///   @@index([field])
+    ///   ^^^^^^^^^^^^^^^^
+    ///   @@map("Bar")
+    ///   ^^^^^^^^^^^^
+    /// }
+    /// ```
+    pub attributes: Vec<Attribute>,
+    /// The documentation for this model.
+    ///
+    /// ```ignore
+    /// /// Lorem ipsum
+    ///     ^^^^^^^^^^^
+    /// model Foo {
+    ///   id    Int    @id
+    ///   field String
+    /// }
+    /// ```
+    pub(crate) documentation: Option<Comment>,
+    /// The regular comments inside the block, in source order. Doc comments
+    /// above a member are its documentation instead.
+    ///
+    /// ```ignore
+    /// model Foo {
+    ///   // Lorem ipsum
+    ///      ^^^^^^^^^^^
+    ///   id Int @id
+    /// }
+    /// ```
+    pub comments: Vec<Comment>,
+    /// The location of this model in the text representation.
+    pub span: Span,
+    /// The span of the inner contents.
+    pub inner_span: Span,
+}
+
+impl Model {
+    /// Iterate over the fields of the model, along with their ids.
+    pub fn iter_fields(&self) -> impl ExactSizeIterator<Item = (FieldId, &Field)> + Clone {
+        self.fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| (FieldId(idx as u32), field))
+    }
+}
+
+impl WithIdentifier for Model {
+    fn identifier(&self) -> &Identifier {
+        &self.name
+    }
+}
+
+impl WithAttributes for Model {
+    fn attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+}
+
+impl WithSpan for Model {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl WithSyntax for Model {
+    fn syntax_kind(&self) -> NodeKind {
+        NodeKind::Model
+    }
+}
+
+impl WithDocumentation for Model {
+    fn documentation(&self) -> Option<&str> {
+        self.documentation.as_ref().map(|doc| doc.text.as_str())
+    }
+}
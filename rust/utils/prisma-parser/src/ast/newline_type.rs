@@ -10,6 +10,21 @@ pub enum NewlineType {
     Windows,
 }
 
+impl NewlineType {
+    /// Detect the dominant line endings of a schema. Schemas without line
+    /// breaks get the default.
+    pub fn detect(schema: &str) -> Self {
+        let windows = schema.matches("\r\n").count();
+        let unix = schema.matches('\n').count() - windows;
+
+        if windows > unix {
+            Self::Windows
+        } else {
+            Self::Unix
+        }
+    }
+}
+
 impl fmt::Display for NewlineType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(self.as_ref())
@@ -24,3 +39,21 @@ impl AsRef<str> for NewlineType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_newline_test() {
+        assert_eq!(
+            NewlineType::detect("model A {\r\n  id Int\r\n}\n"),
+            NewlineType::Windows
+        );
+        assert_eq!(
+            NewlineType::detect("model A {\n  id Int\r\n}\n"),
+            NewlineType::Unix
+        );
+        assert_eq!(NewlineType::detect(""), NewlineType::Unix);
+    }
+}
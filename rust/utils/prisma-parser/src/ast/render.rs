@@ -0,0 +1,538 @@
+//! Rendering the AST back to PSL.
+//!
+//! The output is valid PSL, but it is not aligned: run it through
+//! [`format()`](crate::format) for that. Spans are ignored, except to put the
+//! free-floating comments of a parsed schema back between its blocks and members.
+
+use super::*;
+use std::fmt::{self, Display, Formatter};
+
+const INDENT: &str = "  ";
+
+/// Prints the schema as PSL. Invalid blocks do not keep their text, so they are
+/// left out: a schema parsed with errors does not round-trip.
+impl Display for SchemaAst {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        enum Item<'a> {
+            Top(&'a Top),
+            Comment(&'a Comment),
+        }
+
+        let mut items: Vec<(Span, Item<'_>)> = self
+            .comments
+            .iter()
+            .map(|comment| (comment.span, Item::Comment(comment)))
+            .chain(
+                self.tops
+                    .iter()
+                    .filter(|top| !matches!(top, Top::Invalid(_)))
+                    .map(|top| (top.span(), Item::Top(top))),
+            )
+            .collect();
+
+        // Built nodes all have empty spans: the comments go first.
+
+        items.sort_by_key(|(span, _)| (span.file_id, span.start));
+
+        let mut previous_was_top = false;
+        for (idx, (_, item)) in items.iter().enumerate() {
+            // Comments stick to the block below them.
+            if idx > 0 && previous_was_top {
+                f.write_str("\n")?;
+            }
+
+            match item {
+                Item::Top(top) => write!(f, "{top}")?,
+                Item::Comment(comment) => fmt_comment(f, "", comment)?,
+            }
+
+            previous_was_top = matches!(item, Item::Top(_));
+        }
+
+        Ok(())
+    }
+}
+
+/// Prints the block followed by a newline. Invalid blocks print nothing.
+impl Display for Top {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Top::Model(model) => {
+                fmt_block(f, "model", &model.name, model.documentation.as_ref(), |f| {
+                    fmt_fields_and_attributes(f, &model.fields, &model.attributes, &model.comments)
+                })
+            }
+            Top::CompositeType(composite_type) => fmt_block(
+                f,
+                "type",
+                &composite_type.name,
+                composite_type.documentation.as_ref(),
+                |f| {
+                    fmt_fields_and_attributes(
+                        f,
+                        &composite_type.fields,
+                        &[],
+                        &composite_type.comments,
+                    )
+                },
+            ),
+            Top::View(view) => fmt_block(f, "view", &view.name, view.documentation.as_ref(), |f| {
+                fmt_fields_and_attributes(f, &view.fields, &view.attributes, &view.comments)
+            }),
+            Top::Enum(r#enum) => fmt_block(
+                f,
+                "enum",
+                &r#enum.name,
+                r#enum.documentation.as_ref(),
+                |f| {
+                    let mut comments = FloatingComments::new(&r#enum.comments);
+
+                    for value in &r#enum.values {
+                        comments.before(f, value.span)?;
+                        fmt_documentation(f, INDENT, value.documentation.as_ref())?;
+                        write!(f, "{INDENT}{}", value.name.name)?;
+                        fmt_field_attributes(f, &value.attributes)?;
+                        fmt_trailing_comment(f, value.trailing_comment.as_ref())?;
+                        f.write_str("\n")?;
+                    }
+                    fmt_block_attributes(f, &r#enum.attributes, &mut comments)?;
+                    comments.rest(f)
+                },
+            ),
+            Top::Generator(generator) => fmt_block(
+                f,
+                "generator",
+                &generator.name,
+                generator.documentation.as_ref(),
+                |f| fmt_properties(f, &generator.properties, &generator.comments),
+            ),
+            Top::Source(source) => fmt_block(
+                f,
+                "datasource",
+                &source.name,
+                source.documentation.as_ref(),
+                |f| fmt_properties(f, &source.properties, &source.comments),
+            ),
+            Top::Invalid(_) => Ok(()),
+        }
+    }
+}
+
+/// Prints the property on one line, without indentation. Its doc comments go on
+/// the lines above it.
+impl Display for ConfigBlockProperty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_documentation(f, "", self.documentation.as_ref())?;
+        f.write_str(&self.name.name)?;
+
+        // A property without a value is still a property, not an invalid line.
+        f.write_str(" =")?;
+        if let Some(value) = &self.value {
+            write!(f, " {value}")?;
+        }
+
+        fmt_trailing_comment(f, self.trailing_comment.as_ref())
+    }
+}
+
+/// Writes the floating comments of a block before the first member that follows
+/// them in the source. The remaining ones go at the end of the block.
+struct FloatingComments<'a>(std::iter::Peekable<std::slice::Iter<'a, Comment>>);
+
+impl<'a> FloatingComments<'a> {
+    fn new(comments: &'a [Comment]) -> Self {
+        FloatingComments(comments.iter().peekable())
+    }
+
+    fn before(&mut self, f: &mut Formatter<'_>, span: Span) -> fmt::Result {
+        while let Some(comment) = self.0.next_if(|comment| comment.span.start < span.start) {
+            fmt_comment(f, INDENT, comment)?;
+        }
+
+        Ok(())
+    }
+
+    fn rest(&mut self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0
+            .try_for_each(|comment| fmt_comment(f, INDENT, comment))
+    }
+}
+
+fn fmt_block(
+    f: &mut Formatter<'_>,
+    keyword: &str,
+    name: &Identifier,
+    documentation: Option<&Comment>,
+    body: impl FnOnce(&mut Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    fmt_documentation(f, "", documentation)?;
+    writeln!(f, "{keyword} {} {{", name.name)?;
+    body(f)?;
+    f.write_str("}\n")
+}
+
+fn fmt_properties(
+    f: &mut Formatter<'_>,
+    properties: &[ConfigBlockProperty],
+    comments: &[Comment],
+) -> fmt::Result {
+    let mut comments = FloatingComments::new(comments);
+
+    for property in properties {
+        comments.before(f, property.span)?;
+
+        // Indent every line, including the doc comments.
+        for line in property.to_string().lines() {
+            writeln!(f, "{INDENT}{line}")?;
+        }
+    }
+
+    comments.rest(f)
+}
+
+fn fmt_fields_and_attributes(
+    f: &mut Formatter<'_>,
+    fields: &[Field],
+    attributes: &[Attribute],
+    comments: &[Comment],
+) -> fmt::Result {
+    let mut comments = FloatingComments::new(comments);
+
+    for field in fields {
+        comments.before(f, field.span)?;
+        fmt_documentation(f, INDENT, field.documentation.as_ref())?;
+        write!(f, "{INDENT}{} ", field.name.name)?;
+
+        match &field.field_type {
+            FieldType::Supported(ident) => f.write_str(&ident.name)?,
+            FieldType::Unsupported(name, _) => {
+                write!(f, "Unsupported({})", crate::string_literal(name))?
+            }
+        }
+
+        match field.arity {
+            FieldArity::Required => {}
+            FieldArity::Optional => f.write_str("?")?,
+            FieldArity::List => f.write_str("[]")?,
+        }
+
+        fmt_field_attributes(f, &field.attributes)?;
+        fmt_trailing_comment(f, field.trailing_comment.as_ref())?;
+        f.write_str("\n")?;
+    }
+
+    if !fields.is_empty() && !attributes.is_empty() {
+        f.write_str("\n")?;
+    }
+
+    fmt_block_attributes(f, attributes, &mut comments)?;
+    comments.rest(f)
+}
+
+fn fmt_block_attributes(
+    f: &mut Formatter<'_>,
+    attributes: &[Attribute],
+    comments: &mut FloatingComments<'_>,
+) -> fmt::Result {
+    for attribute in attributes {
+        comments.before(f, attribute.span)?;
+        f.write_str(INDENT)?;
+        fmt_attribute(f, "@@", attribute)?;
+        f.write_str("\n")?;
+    }
+
+    Ok(())
+}
+
+fn fmt_field_attributes(f: &mut Formatter<'_>, attributes: &[Attribute]) -> fmt::Result {
+    attributes
+        .iter()
+        .try_for_each(|attribute| fmt_attribute(f, " @", attribute))
+}
+
+/// Write the attribute after the given prefix, e.g. `"@@"` for block attributes.
+fn fmt_attribute(f: &mut Formatter<'_>, prefix: &str, attribute: &Attribute) -> fmt::Result {
+    write!(f, "{prefix}{}", attribute.name.name)?;
+
+    let arguments = &attribute.arguments;
+    if arguments.arguments.is_empty() && arguments.empty_arguments.is_empty() {
+        return Ok(());
+    }
+
+    // Empty arguments are in a list of their own: sort them back in place.
+    let mut arguments: Vec<(Span, String)> = arguments
+        .arguments
+        .iter()
+        .map(|argument| match &argument.name {
+            Some(name) => (argument.span, format!("{}: {}", name.name, argument.value)),
+            None => (argument.span, argument.value.to_string()),
+        })
+        .chain(
+            arguments
+                .empty_arguments
+                .iter()
+                .map(|argument| (argument.name.span, format!("{}:", argument.name.name))),
+        )
+        .collect();
+    arguments.sort_by_key(|(span, _)| span.start);
+
+    let arguments = arguments
+        .into_iter()
+        .map(|(_, argument)| argument)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    write!(f, "({arguments})")
+}
+
+fn fmt_documentation(
+    f: &mut Formatter<'_>,
+    indent: &str,
+    documentation: Option<&Comment>,
+) -> fmt::Result {
+    match documentation {
+        Some(comment) => fmt_comment(f, indent, comment),
+        None => Ok(()),
+    }
+}
+
+/// Write a comment on its own lines, one line per line of text.
+fn fmt_comment(f: &mut Formatter<'_>, indent: &str, comment: &Comment) -> fmt::Result {
+    for line in comment.text.split('\n') {
+        f.write_str(indent)?;
+        fmt_comment_line(f, comment.is_doc, line)?;
+        f.write_str("\n")?;
+    }
+
+    Ok(())
+}
+
+/// Write the comment at the end of the line, after a space.
+fn fmt_trailing_comment(f: &mut Formatter<'_>, comment: Option<&Comment>) -> fmt::Result {
+    match comment {
+        Some(comment) => {
+            f.write_str(" ")?;
+            fmt_comment_line(f, comment.is_doc, &comment.text)
+        }
+        None => Ok(()),
+    }
+}
+
+fn fmt_comment_line(f: &mut Formatter<'_>, is_doc: bool, text: &str) -> fmt::Result {
+    let slashes = if is_doc { "///" } else { "//" };
+
+    if text.is_empty() {
+        f.write_str(slashes)
+    } else {
+        write!(f, "{slashes} {text}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostics::Diagnostics, parse_schema, parse_schemas};
+
+    #[test]
+    fn render_built_schema_test() {
+        let schema = SchemaAst::builder()
+            .comment("Generated.")
+            .top(
+                SourceConfig::builder("db")
+                    .documentation("The database.")
+                    .property("provider", Expression::string("postgres"))
+                    .property(
+                        "url",
+                        Expression::function("env", vec![Expression::string("DATABASE_URL")]),
+                    )
+                    .build(),
+            )
+            .top(
+                Model::builder("User")
+                    .field(
+                        Field::builder("id", "Int")
+                            .attribute(Attribute::new("id", vec![]))
+                            .build(),
+                    )
+                    .field(
+                        Field::builder("name", "String")
+                            .arity(FieldArity::Optional)
+                            .documentation("Say \"hi\".")
+                            .build(),
+                    )
+                    .attribute(Attribute::new(
+                        "map",
+                        vec![Argument::named("name", Expression::string("users"))],
+                    ))
+                    .build(),
+            )
+            .top(Enum::builder("Role").value("USER").value("ADMIN").build())
+            .build();
+
+        let expected = r#"// Generated.
+/// The database.
+datasource db {
+  provider = "postgres"
+  url = env("DATABASE_URL")
+}
+
+model User {
+  id Int @id
+  /// Say "hi".
+  name String?
+
+  @@map(name: "users")
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+"#;
+
+        assert_eq!(schema.to_string(), expected);
+    }
+
+    #[test]
+    fn render_parsed_schema_test() {
+        let input = r#"
+// Free floating.
+generator client {
+  provider = "prisma-client-js" // the client
+  /// Where it goes.
+  output   = "../client\n"
+  previewFeatures = ["views", "fullTextSearch"]
+}
+
+model Post {
+  id     Int     @id @default(autoincrement())
+  title  String  @db.VarChar(200)
+  tags   String[]
+  geo    Unsupported("point")?
+  author User    @relation(fields: [authorId], references: [id], onDelete: Cascade)
+
+  @@index([title], map: "idx")
+}
+"#;
+        let ast = parse_schema(input, &mut Diagnostics::new());
+        let rendered = ast.to_string();
+
+        assert!(rendered.starts_with("// Free floating.\ngenerator client {\n"));
+        assert!(rendered.contains("  provider = \"prisma-client-js\" // the client\n"));
+        assert!(rendered.contains("  /// Where it goes.\n  output = \"../client\\n\"\n"));
+        assert!(rendered.contains("  geo Unsupported(\"point\")?\n"));
+        assert!(rendered.contains(
+            "  author User @relation(fields: [authorId], references: [id], onDelete: Cascade)\n"
+        ));
+
+        // Rendering is stable once the formatting is normalized.
+        let mut diagnostics = Diagnostics::new();
+        let reparsed = parse_schema(&rendered, &mut diagnostics);
+        assert!(!diagnostics.has_errors());
+        assert_eq!(reparsed.to_string(), rendered);
+    }
+
+    #[test]
+    fn render_comments_in_blocks_test() {
+        let input = r#"datasource db {
+  // Regular.
+  provider = "postgres"
+}
+
+model User {
+  id Int @id // The key.
+  // Between fields.
+  name String
+
+  // Before the attributes.
+  @@map("users")
+  // At the end.
+}
+
+enum Role {
+  USER // Trailing.
+}
+"#;
+        let ast = parse_schema(input, &mut Diagnostics::new());
+
+        assert_eq!(ast.to_string(), input);
+    }
+
+    #[test]
+    fn render_multiple_files_test() {
+        let files = [
+            ("a.prisma", "model A {\n  id Int @id\n}\n"),
+            ("b.prisma", "// B.\nmodel B {\n  id Int @id\n}\n"),
+        ];
+        let ast = parse_schemas(&files, &mut Diagnostics::new());
+
+        assert_eq!(ast.to_string(), format!("{}\n{}", files[0].1, files[1].1));
+    }
+
+    #[test]
+    fn render_built_composite_type_and_view_test() {
+        let schema = SchemaAst::builder()
+            .top(
+                CompositeType::builder("Address")
+                    .field(Field::builder("street", "String").build())
+                    .build(),
+            )
+            .top(
+                View::builder("Admin")
+                    .field(Field::builder("id", "Int").build())
+                    .attribute(Attribute::new(
+                        "map",
+                        vec![Argument::unnamed(Expression::string("admins"))],
+                    ))
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(
+            schema.to_string(),
+            "type Address {\n  street String\n}\n\nview Admin {\n  id Int\n\n  @@map(\"admins\")\n}\n"
+        );
+    }
+
+    #[test]
+    fn render_numbers_test() {
+        let numbers = Expression::array(vec![
+            Expression::number(42u8),
+            Expression::number(-7i64),
+            Expression::float(1.5).unwrap(),
+            Expression::float(2.0).unwrap(),
+        ]);
+
+        assert_eq!(numbers.to_string(), "[42,-7,1.5,2]");
+        assert!(Expression::float(f64::NAN).is_none());
+        assert!(Expression::float(f64::INFINITY).is_none());
+    }
+
+    #[test]
+    fn render_arguments_and_empty_properties_test() {
+        let input = r#"generator client {
+  provider = // not yet
+}
+
+model Post {
+  authorId Int
+  author   User @relation(onDelete: , fields: [authorId], references: [id])
+
+  @@map(name: "posts")
+}
+"#;
+        let ast = parse_schema(input, &mut Diagnostics::new());
+        let rendered = ast.to_string();
+
+        assert!(rendered.contains("  provider = // not yet\n"), "{rendered}");
+        assert!(
+            rendered.contains("@relation(onDelete:, fields: [authorId], references: [id])"),
+            "{rendered}"
+        );
+        assert!(rendered.contains("@@map(name: \"posts\")"), "{rendered}");
+
+        let mut diagnostics = Diagnostics::new();
+        parse_schema(&rendered, &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{diagnostics:?}");
+    }
+}
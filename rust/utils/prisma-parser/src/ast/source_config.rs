@@ -1,7 +1,10 @@
-use super::{Comment, ConfigBlockProperty, Identifier, Span, WithDocumentation, WithIdentifier, WithSpan};
+use super::{Comment, ConfigBlockProperty, Identifier, Span, WithDocumentation, WithIdentifier, WithSpan, WithSyntax};
+use crate::cst::NodeKind;
 
 /// A source block declaration.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct SourceConfig {
     /// Name of this source.
     pub name: Identifier,
@@ -9,6 +12,9 @@ pub struct SourceConfig {
     pub properties: Vec<ConfigBlockProperty>,
     /// The comments for this source block.
     pub(crate) documentation: Option<Comment>,
+    /// The regular comments inside the block, in source order. Doc comments
+    /// above a property are its documentation instead.
+    pub comments: Vec<Comment>,
     /// The location of this source block in the text representation.
     pub span: Span,
     /// The span of the inner contents.
@@ -27,6 +33,12 @@ impl WithSpan for SourceConfig {
     }
 }
 
+impl WithSyntax for SourceConfig {
+    fn syntax_kind(&self) -> NodeKind {
+        NodeKind::Datasource
+    }
+}
+
 impl WithDocumentation for SourceConfig {
     fn documentation(&self) -> Option<&str> {
         self.documentation.as_ref().map(|doc| doc.text.as_str())
@@ -1,39 +1,127 @@
-use crate::ast::{traits::WithSpan, SourceConfig, Span};
+use crate::ast::{
+    traits::{WithSpan, WithSyntax},
+    CompositeType, Enum, FileId, GeneratorConfig, InvalidBlock, Model, SourceConfig, Span, View,
+};
 
 use super::Identifier;
+use crate::cst::NodeKind;
+
+/// The identifier of invalid blocks without a name.
+static NO_NAME: Identifier = Identifier {
+    name: String::new(),
+    span: Span {
+        start: 0,
+        end: 0,
+        file_id: FileId::ZERO,
+    },
+};
 
 /// Enum for distinguishing between top-level entries
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "camelCase"))]
 pub enum Top {
+    /// A model declaration
+    Model(Model),
+    /// A composite type declaration
+    CompositeType(CompositeType),
+    /// A view declaration
+    View(View),
+    /// An enum declaration
+    Enum(Enum),
+    /// A generator block
+    Generator(GeneratorConfig),
     /// A datasource block
     Source(SourceConfig),
+    /// A block that could not be parsed
+    Invalid(InvalidBlock),
 }
 
 impl Top {
     /// A string saying what kind of item this is.
     pub fn get_type(&self) -> &str {
         match self {
+            Top::Model(_) => "model",
+            Top::CompositeType(_) => "composite type",
+            Top::View(_) => "view",
+            Top::Enum(_) => "enum",
+            Top::Generator(_) => "generator",
             Top::Source(_) => "source",
+            Top::Invalid(_) => "invalid block",
         }
     }
 
-    /// The name of the item.
+    /// The identifier of the item. Invalid blocks without a name have an empty one.
     pub fn identifier(&self) -> &Identifier {
         match self {
+            Top::Model(x) => &x.name,
+            Top::CompositeType(x) => &x.name,
+            Top::View(x) => &x.name,
+            Top::Enum(x) => &x.name,
+            Top::Generator(x) => &x.name,
             Top::Source(x) => &x.name,
+            Top::Invalid(x) => x.name.as_ref().unwrap_or(&NO_NAME),
         }
     }
 
-    /// The name of the item.
+    /// The name of the item. Invalid blocks without a name have an empty one.
     pub fn name(&self) -> &str {
         &self.identifier().name
     }
 
+    /// Try to interpret the item as a model declaration.
+    pub fn as_model(&self) -> Option<&Model> {
+        match self {
+            Top::Model(model) => Some(model),
+            _ => None,
+        }
+    }
+
+    /// Try to interpret the item as a composite type declaration.
+    pub fn as_composite_type(&self) -> Option<&CompositeType> {
+        match self {
+            Top::CompositeType(composite_type) => Some(composite_type),
+            _ => None,
+        }
+    }
+
+    /// Try to interpret the item as a view declaration.
+    pub fn as_view(&self) -> Option<&View> {
+        match self {
+            Top::View(view) => Some(view),
+            _ => None,
+        }
+    }
+
+    /// Try to interpret the item as an enum declaration.
+    pub fn as_enum(&self) -> Option<&Enum> {
+        match self {
+            Top::Enum(r#enum) => Some(r#enum),
+            _ => None,
+        }
+    }
+
+    /// Try to interpret the item as a generator block.
+    pub fn as_generator(&self) -> Option<&GeneratorConfig> {
+        match self {
+            Top::Generator(generator) => Some(generator),
+            _ => None,
+        }
+    }
+
     /// Try to interpret the item as a datasource block.
     pub fn as_source(&self) -> Option<&SourceConfig> {
         match self {
             Top::Source(source) => Some(source),
-            // _ => None,
+            _ => None,
+        }
+    }
+
+    /// Try to interpret the item as a block that could not be parsed.
+    pub fn as_invalid_block(&self) -> Option<&InvalidBlock> {
+        match self {
+            Top::Invalid(invalid_block) => Some(invalid_block),
+            _ => None,
         }
     }
 }
@@ -41,7 +129,27 @@ impl Top {
 impl WithSpan for Top {
     fn span(&self) -> Span {
         match self {
+            Top::Model(model) => model.span(),
+            Top::CompositeType(composite_type) => composite_type.span(),
+            Top::View(view) => view.span(),
+            Top::Enum(r#enum) => r#enum.span(),
+            Top::Generator(generator) => generator.span(),
             Top::Source(source) => source.span(),
+            Top::Invalid(invalid_block) => invalid_block.span(),
+        }
+    }
+}
+
+impl WithSyntax for Top {
+    fn syntax_kind(&self) -> NodeKind {
+        match self {
+            Top::Model(model) => model.syntax_kind(),
+            Top::CompositeType(composite_type) => composite_type.syntax_kind(),
+            Top::View(view) => view.syntax_kind(),
+            Top::Enum(r#enum) => r#enum.syntax_kind(),
+            Top::Generator(generator) => generator.syntax_kind(),
+            Top::Source(source) => source.syntax_kind(),
+            Top::Invalid(invalid_block) => invalid_block.syntax_kind(),
         }
     }
 }
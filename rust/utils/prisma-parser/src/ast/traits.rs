@@ -1,4 +1,5 @@
-use super::{Identifier, Span};
+use super::{Attribute, Identifier, Span};
+use crate::cst::{NodeKind, SyntaxNodePtr};
 
 /// An AST node with a span.
 pub trait WithSpan {
@@ -6,6 +7,22 @@ pub trait WithSpan {
     fn span(&self) -> Span;
 }
 
+/// An AST node with its own node in the concrete syntax tree: blocks, fields, enum
+/// values and properties.
+pub trait WithSyntax: WithSpan {
+    /// The kind of the concrete syntax node.
+    fn syntax_kind(&self) -> NodeKind;
+
+    /// A handle to the concrete syntax node, in the tree parsed from the same text.
+    /// Nodes built in code have no text, their handles resolve to nothing.
+    fn syntax_ptr(&self) -> SyntaxNodePtr {
+        SyntaxNodePtr {
+            kind: self.syntax_kind(),
+            span: self.span(),
+        }
+    }
+}
+
 /// An AST node with a name (from the identifier).
 pub trait WithName {
     /// The name of the item.
@@ -18,6 +35,12 @@ pub trait WithIdentifier {
     fn identifier(&self) -> &Identifier;
 }
 
+/// An AST node with attributes.
+pub trait WithAttributes {
+    /// The attributes of the item.
+    fn attributes(&self) -> &[Attribute];
+}
+
 /// An AST node with documentation.
 pub trait WithDocumentation {
     /// The documentation string, if defined.
@@ -0,0 +1,119 @@
+use super::{
+    Attribute, Comment, Field, FieldId, Identifier, Span, WithAttributes, WithDocumentation,
+    WithIdentifier, WithSpan, WithSyntax,
+};
+use crate::cst::NodeKind;
+
+impl std::ops::Index<FieldId> for View {
+    type Output = Field;
+
+    fn index(&self, index: FieldId) -> &Self::Output {
+        &self.fields[index.0 as usize]
+    }
+}
+
+/// A view declaration. At the syntax level, a view has the same shape as a
+/// model, but it is backed by a database view rather than a table.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct View {
+    /// The name of the view.
+    ///
+    /// ```ignore
+    /// view Foo { .. }
+    ///      ^^^
+    /// ```
+    pub name: Identifier,
+    /// The fields of the view.
+    ///
+    /// ```ignore
+    /// view Foo {
+    ///   id    Int    @unique
+    ///   ^^^^^^^^^^^^^^^^^^^^
+    ///   field String
+    ///   ^^^^^^^^^^^^
+    /// }
+    /// ```
+    pub fields: Vec<Field>,
+    /// The attributes of this view.
+    ///
+    /// ```ignore
+    /// view Foo {
+    ///   id    Int    @id
+    ///   field String
+    ///
+    ///   @@index([field])
+    ///   ^^^^^^^^^^^^^^^^
+    ///   @@map("Bar")
+    ///   ^^^^^^^^^^^^
+    /// }
+    /// ```
+    pub attributes: Vec<Attribute>,
+    /// The documentation for this view.
+    ///
+    /// ```ignore
+    /// /// Lorem ipsum
+    ///     ^^^^^^^^^^^
+    /// view Foo {
+    ///   id    Int    @unique
+    ///   field String
+    /// }
+    /// ```
+    pub(crate) documentation: Option<Comment>,
+    /// The regular comments inside the block, in source order. Doc comments
+    /// above a member are its documentation instead.
+    ///
+    /// ```ignore
+    /// view Foo {
+    ///   // Lorem ipsum
+    ///      ^^^^^^^^^^^
+    ///   id Int
+    /// }
+    /// ```
+    pub comments: Vec<Comment>,
+    /// The location of this view in the text representation.
+    pub span: Span,
+    /// The span of the inner contents.
+    pub inner_span: Span,
+}
+
+impl View {
+    /// Iterate over the fields of the view, along with their ids.
+    pub fn iter_fields(&self) -> impl ExactSizeIterator<Item = (FieldId, &Field)> + Clone {
+        self.fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| (FieldId(idx as u32), field))
+    }
+}
+
+impl WithIdentifier for View {
+    fn identifier(&self) -> &Identifier {
+        &self.name
+    }
+}
+
+impl WithAttributes for View {
+    fn attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+}
+
+impl WithSpan for View {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl WithSyntax for View {
+    fn syntax_kind(&self) -> NodeKind {
+        NodeKind::View
+    }
+}
+
+impl WithDocumentation for View {
+    fn documentation(&self) -> Option<&str> {
+        self.documentation.as_ref().map(|doc| doc.text.as_str())
+    }
+}
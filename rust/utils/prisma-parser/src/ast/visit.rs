@@ -0,0 +1,713 @@
+//! Traversals over the schema AST.
+//!
+//! Implement [`Visit`] or [`VisitMut`] and override the methods for the nodes you
+//! care about. Each default method calls the matching `walk_*` function, which
+//! visits the children of the node; call it from an overridden method to keep
+//! descending.
+//!
+//! The children are visited grouped by kind, in the order documented on each
+//! `walk_*` function, and in source order within a group. This is not always the
+//! source order overall: the attributes of a model are visited after all of its
+//! fields, and the comments inside a block after its members.
+//!
+//! ```
+//! use prisma_parser::ast::{visit::Visit, Identifier};
+//!
+//! struct Names(Vec<String>);
+//!
+//! impl<'ast> Visit<'ast> for Names {
+//!     fn visit_identifier(&mut self, identifier: &'ast Identifier) {
+//!         self.0.push(identifier.name.clone());
+//!     }
+//! }
+//! ```
+
+use super::*;
+
+/// A read-only traversal of the AST.
+pub trait Visit<'ast> {
+    fn visit_schema(&mut self, schema: &'ast SchemaAst) {
+        walk_schema(self, schema);
+    }
+
+    fn visit_top(&mut self, top: &'ast Top) {
+        walk_top(self, top);
+    }
+
+    fn visit_model(&mut self, model: &'ast Model) {
+        walk_model(self, model);
+    }
+
+    fn visit_composite_type(&mut self, composite_type: &'ast CompositeType) {
+        walk_composite_type(self, composite_type);
+    }
+
+    fn visit_view(&mut self, view: &'ast View) {
+        walk_view(self, view);
+    }
+
+    fn visit_field(&mut self, field: &'ast Field) {
+        walk_field(self, field);
+    }
+
+    fn visit_enum(&mut self, r#enum: &'ast Enum) {
+        walk_enum(self, r#enum);
+    }
+
+    fn visit_enum_value(&mut self, value: &'ast EnumValue) {
+        walk_enum_value(self, value);
+    }
+
+    fn visit_attribute(&mut self, attribute: &'ast Attribute) {
+        walk_attribute(self, attribute);
+    }
+
+    fn visit_arguments_list(&mut self, arguments: &'ast ArgumentsList) {
+        walk_arguments_list(self, arguments);
+    }
+
+    fn visit_argument(&mut self, argument: &'ast Argument) {
+        walk_argument(self, argument);
+    }
+
+    fn visit_expression(&mut self, expression: &'ast Expression) {
+        walk_expression(self, expression);
+    }
+
+    fn visit_source_config(&mut self, source: &'ast SourceConfig) {
+        walk_source_config(self, source);
+    }
+
+    fn visit_generator_config(&mut self, generator: &'ast GeneratorConfig) {
+        walk_generator_config(self, generator);
+    }
+
+    fn visit_config_block_property(&mut self, property: &'ast ConfigBlockProperty) {
+        walk_config_block_property(self, property);
+    }
+
+    fn visit_invalid_block(&mut self, invalid_block: &'ast InvalidBlock) {
+        walk_invalid_block(self, invalid_block);
+    }
+
+    fn visit_identifier(&mut self, identifier: &'ast Identifier) {
+        let _ = identifier;
+    }
+
+    fn visit_comment(&mut self, comment: &'ast Comment) {
+        let _ = comment;
+    }
+}
+
+/// Visits the tops, then the free-floating comments.
+pub fn walk_schema<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, schema: &'ast SchemaAst) {
+    for top in &schema.tops {
+        v.visit_top(top);
+    }
+    for comment in &schema.comments {
+        v.visit_comment(comment);
+    }
+}
+
+/// Visits the block of the top.
+pub fn walk_top<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, top: &'ast Top) {
+    match top {
+        Top::Model(model) => v.visit_model(model),
+        Top::CompositeType(composite_type) => v.visit_composite_type(composite_type),
+        Top::View(view) => v.visit_view(view),
+        Top::Enum(r#enum) => v.visit_enum(r#enum),
+        Top::Generator(generator) => v.visit_generator_config(generator),
+        Top::Source(source) => v.visit_source_config(source),
+        Top::Invalid(invalid_block) => v.visit_invalid_block(invalid_block),
+    }
+}
+
+/// Visits the documentation, the name, the fields, the block attributes, then
+/// the comments inside the model.
+pub fn walk_model<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, model: &'ast Model) {
+    if let Some(comment) = &model.documentation {
+        v.visit_comment(comment);
+    }
+    v.visit_identifier(&model.name);
+    for field in &model.fields {
+        v.visit_field(field);
+    }
+    for attribute in &model.attributes {
+        v.visit_attribute(attribute);
+    }
+    for comment in &model.comments {
+        v.visit_comment(comment);
+    }
+}
+
+/// Visits the documentation, the name, the fields, then the comments inside
+/// the type.
+pub fn walk_composite_type<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    composite_type: &'ast CompositeType,
+) {
+    if let Some(comment) = &composite_type.documentation {
+        v.visit_comment(comment);
+    }
+    v.visit_identifier(&composite_type.name);
+    for field in &composite_type.fields {
+        v.visit_field(field);
+    }
+    for comment in &composite_type.comments {
+        v.visit_comment(comment);
+    }
+}
+
+/// Visits the documentation, the name, the fields, the block attributes, then
+/// the comments inside the view.
+pub fn walk_view<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, view: &'ast View) {
+    if let Some(comment) = &view.documentation {
+        v.visit_comment(comment);
+    }
+    v.visit_identifier(&view.name);
+    for field in &view.fields {
+        v.visit_field(field);
+    }
+    for attribute in &view.attributes {
+        v.visit_attribute(attribute);
+    }
+    for comment in &view.comments {
+        v.visit_comment(comment);
+    }
+}
+
+/// Visits the documentation, the name, the type unless it is `Unsupported(...)`,
+/// the attributes, then the trailing comment.
+pub fn walk_field<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, field: &'ast Field) {
+    if let Some(comment) = &field.documentation {
+        v.visit_comment(comment);
+    }
+    v.visit_identifier(&field.name);
+    if let FieldType::Supported(identifier) = &field.field_type {
+        v.visit_identifier(identifier);
+    }
+    for attribute in &field.attributes {
+        v.visit_attribute(attribute);
+    }
+    if let Some(comment) = &field.trailing_comment {
+        v.visit_comment(comment);
+    }
+}
+
+/// Visits the documentation, the name, the values, the block attributes, then
+/// the comments inside the enum.
+pub fn walk_enum<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, r#enum: &'ast Enum) {
+    if let Some(comment) = &r#enum.documentation {
+        v.visit_comment(comment);
+    }
+    v.visit_identifier(&r#enum.name);
+    for value in &r#enum.values {
+        v.visit_enum_value(value);
+    }
+    for attribute in &r#enum.attributes {
+        v.visit_attribute(attribute);
+    }
+    for comment in &r#enum.comments {
+        v.visit_comment(comment);
+    }
+}
+
+/// Visits the documentation, the name, the attributes, then the trailing comment.
+pub fn walk_enum_value<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, value: &'ast EnumValue) {
+    if let Some(comment) = &value.documentation {
+        v.visit_comment(comment);
+    }
+    v.visit_identifier(&value.name);
+    for attribute in &value.attributes {
+        v.visit_attribute(attribute);
+    }
+    if let Some(comment) = &value.trailing_comment {
+        v.visit_comment(comment);
+    }
+}
+
+/// Visits the name, then the arguments.
+pub fn walk_attribute<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, attribute: &'ast Attribute) {
+    v.visit_identifier(&attribute.name);
+    v.visit_arguments_list(&attribute.arguments);
+}
+
+/// Visits the arguments, then the names of the empty arguments like `map:`.
+pub fn walk_arguments_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    arguments: &'ast ArgumentsList,
+) {
+    for argument in &arguments.arguments {
+        v.visit_argument(argument);
+    }
+    for argument in &arguments.empty_arguments {
+        v.visit_identifier(&argument.name);
+    }
+}
+
+/// Visits the name of a named argument, then the value.
+pub fn walk_argument<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, argument: &'ast Argument) {
+    if let Some(name) = &argument.name {
+        v.visit_identifier(name);
+    }
+    v.visit_expression(&argument.value);
+}
+
+/// Visits the name and the arguments of a function, or the values of an array.
+/// Other expressions have no children.
+pub fn walk_expression<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, expression: &'ast Expression) {
+    match expression {
+        Expression::Function(name, arguments, _) => {
+            v.visit_identifier(name);
+            v.visit_arguments_list(arguments);
+        }
+        Expression::Array(values, _) => {
+            for value in values {
+                v.visit_expression(value);
+            }
+        }
+        Expression::NumericValue(..)
+        | Expression::StringValue(..)
+        | Expression::ConstantValue(..) => {}
+    }
+}
+
+/// Visits the documentation, the name, the properties, then the comments inside
+/// the block.
+pub fn walk_source_config<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, source: &'ast SourceConfig) {
+    if let Some(comment) = &source.documentation {
+        v.visit_comment(comment);
+    }
+    v.visit_identifier(&source.name);
+    for property in &source.properties {
+        v.visit_config_block_property(property);
+    }
+    for comment in &source.comments {
+        v.visit_comment(comment);
+    }
+}
+
+/// Visits the documentation, the name, the properties, then the comments inside
+/// the block.
+pub fn walk_generator_config<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    generator: &'ast GeneratorConfig,
+) {
+    if let Some(comment) = &generator.documentation {
+        v.visit_comment(comment);
+    }
+    v.visit_identifier(&generator.name);
+    for property in &generator.properties {
+        v.visit_config_block_property(property);
+    }
+    for comment in &generator.comments {
+        v.visit_comment(comment);
+    }
+}
+
+/// Visits the documentation, the name, the value, then the trailing comment.
+pub fn walk_config_block_property<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    property: &'ast ConfigBlockProperty,
+) {
+    if let Some(comment) = &property.documentation {
+        v.visit_comment(comment);
+    }
+    v.visit_identifier(&property.name);
+    if let Some(value) = &property.value {
+        v.visit_expression(value);
+    }
+    if let Some(comment) = &property.trailing_comment {
+        v.visit_comment(comment);
+    }
+}
+
+/// Visits the name, if the block has one.
+pub fn walk_invalid_block<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    invalid_block: &'ast InvalidBlock,
+) {
+    if let Some(name) = &invalid_block.name {
+        v.visit_identifier(name);
+    }
+}
+
+/// A traversal of the AST that can modify the nodes in place.
+pub trait VisitMut {
+    fn visit_schema_mut(&mut self, schema: &mut SchemaAst) {
+        walk_schema_mut(self, schema);
+    }
+
+    fn visit_top_mut(&mut self, top: &mut Top) {
+        walk_top_mut(self, top);
+    }
+
+    fn visit_model_mut(&mut self, model: &mut Model) {
+        walk_model_mut(self, model);
+    }
+
+    fn visit_composite_type_mut(&mut self, composite_type: &mut CompositeType) {
+        walk_composite_type_mut(self, composite_type);
+    }
+
+    fn visit_view_mut(&mut self, view: &mut View) {
+        walk_view_mut(self, view);
+    }
+
+    fn visit_field_mut(&mut self, field: &mut Field) {
+        walk_field_mut(self, field);
+    }
+
+    fn visit_enum_mut(&mut self, r#enum: &mut Enum) {
+        walk_enum_mut(self, r#enum);
+    }
+
+    fn visit_enum_value_mut(&mut self, value: &mut EnumValue) {
+        walk_enum_value_mut(self, value);
+    }
+
+    fn visit_attribute_mut(&mut self, attribute: &mut Attribute) {
+        walk_attribute_mut(self, attribute);
+    }
+
+    fn visit_arguments_list_mut(&mut self, arguments: &mut ArgumentsList) {
+        walk_arguments_list_mut(self, arguments);
+    }
+
+    fn visit_argument_mut(&mut self, argument: &mut Argument) {
+        walk_argument_mut(self, argument);
+    }
+
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        walk_expression_mut(self, expression);
+    }
+
+    fn visit_source_config_mut(&mut self, source: &mut SourceConfig) {
+        walk_source_config_mut(self, source);
+    }
+
+    fn visit_generator_config_mut(&mut self, generator: &mut GeneratorConfig) {
+        walk_generator_config_mut(self, generator);
+    }
+
+    fn visit_config_block_property_mut(&mut self, property: &mut ConfigBlockProperty) {
+        walk_config_block_property_mut(self, property);
+    }
+
+    fn visit_invalid_block_mut(&mut self, invalid_block: &mut InvalidBlock) {
+        walk_invalid_block_mut(self, invalid_block);
+    }
+
+    fn visit_identifier_mut(&mut self, identifier: &mut Identifier) {
+        let _ = identifier;
+    }
+
+    fn visit_comment_mut(&mut self, comment: &mut Comment) {
+        let _ = comment;
+    }
+}
+
+/// Visits the tops, then the free-floating comments.
+pub fn walk_schema_mut<V: VisitMut + ?Sized>(v: &mut V, schema: &mut SchemaAst) {
+    for top in &mut schema.tops {
+        v.visit_top_mut(top);
+    }
+    for comment in &mut schema.comments {
+        v.visit_comment_mut(comment);
+    }
+}
+
+/// Like [`walk_top()`], with mutable nodes.
+pub fn walk_top_mut<V: VisitMut + ?Sized>(v: &mut V, top: &mut Top) {
+    match top {
+        Top::Model(model) => v.visit_model_mut(model),
+        Top::CompositeType(composite_type) => v.visit_composite_type_mut(composite_type),
+        Top::View(view) => v.visit_view_mut(view),
+        Top::Enum(r#enum) => v.visit_enum_mut(r#enum),
+        Top::Generator(generator) => v.visit_generator_config_mut(generator),
+        Top::Source(source) => v.visit_source_config_mut(source),
+        Top::Invalid(invalid_block) => v.visit_invalid_block_mut(invalid_block),
+    }
+}
+
+/// Like [`walk_model()`], with mutable nodes.
+pub fn walk_model_mut<V: VisitMut + ?Sized>(v: &mut V, model: &mut Model) {
+    if let Some(comment) = &mut model.documentation {
+        v.visit_comment_mut(comment);
+    }
+    v.visit_identifier_mut(&mut model.name);
+    for field in &mut model.fields {
+        v.visit_field_mut(field);
+    }
+    for attribute in &mut model.attributes {
+        v.visit_attribute_mut(attribute);
+    }
+    for comment in &mut model.comments {
+        v.visit_comment_mut(comment);
+    }
+}
+
+/// Like [`walk_composite_type()`], with mutable nodes.
+pub fn walk_composite_type_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    composite_type: &mut CompositeType,
+) {
+    if let Some(comment) = &mut composite_type.documentation {
+        v.visit_comment_mut(comment);
+    }
+    v.visit_identifier_mut(&mut composite_type.name);
+    for field in &mut composite_type.fields {
+        v.visit_field_mut(field);
+    }
+    for comment in &mut composite_type.comments {
+        v.visit_comment_mut(comment);
+    }
+}
+
+/// Like [`walk_view()`], with mutable nodes.
+pub fn walk_view_mut<V: VisitMut + ?Sized>(v: &mut V, view: &mut View) {
+    if let Some(comment) = &mut view.documentation {
+        v.visit_comment_mut(comment);
+    }
+    v.visit_identifier_mut(&mut view.name);
+    for field in &mut view.fields {
+        v.visit_field_mut(field);
+    }
+    for attribute in &mut view.attributes {
+        v.visit_attribute_mut(attribute);
+    }
+    for comment in &mut view.comments {
+        v.visit_comment_mut(comment);
+    }
+}
+
+/// Like [`walk_field()`], with mutable nodes.
+pub fn walk_field_mut<V: VisitMut + ?Sized>(v: &mut V, field: &mut Field) {
+    if let Some(comment) = &mut field.documentation {
+        v.visit_comment_mut(comment);
+    }
+    v.visit_identifier_mut(&mut field.name);
+    if let FieldType::Supported(identifier) = &mut field.field_type {
+        v.visit_identifier_mut(identifier);
+    }
+    for attribute in &mut field.attributes {
+        v.visit_attribute_mut(attribute);
+    }
+    if let Some(comment) = &mut field.trailing_comment {
+        v.visit_comment_mut(comment);
+    }
+}
+
+/// Like [`walk_enum()`], with mutable nodes.
+pub fn walk_enum_mut<V: VisitMut + ?Sized>(v: &mut V, r#enum: &mut Enum) {
+    if let Some(comment) = &mut r#enum.documentation {
+        v.visit_comment_mut(comment);
+    }
+    v.visit_identifier_mut(&mut r#enum.name);
+    for value in &mut r#enum.values {
+        v.visit_enum_value_mut(value);
+    }
+    for attribute in &mut r#enum.attributes {
+        v.visit_attribute_mut(attribute);
+    }
+    for comment in &mut r#enum.comments {
+        v.visit_comment_mut(comment);
+    }
+}
+
+/// Like [`walk_enum_value()`], with mutable nodes.
+pub fn walk_enum_value_mut<V: VisitMut + ?Sized>(v: &mut V, value: &mut EnumValue) {
+    if let Some(comment) = &mut value.documentation {
+        v.visit_comment_mut(comment);
+    }
+    v.visit_identifier_mut(&mut value.name);
+    for attribute in &mut value.attributes {
+        v.visit_attribute_mut(attribute);
+    }
+    if let Some(comment) = &mut value.trailing_comment {
+        v.visit_comment_mut(comment);
+    }
+}
+
+/// Like [`walk_attribute()`], with mutable nodes.
+pub fn walk_attribute_mut<V: VisitMut + ?Sized>(v: &mut V, attribute: &mut Attribute) {
+    v.visit_identifier_mut(&mut attribute.name);
+    v.visit_arguments_list_mut(&mut attribute.arguments);
+}
+
+/// Like [`walk_arguments_list()`], with mutable nodes.
+pub fn walk_arguments_list_mut<V: VisitMut + ?Sized>(v: &mut V, arguments: &mut ArgumentsList) {
+    for argument in &mut arguments.arguments {
+        v.visit_argument_mut(argument);
+    }
+    for argument in &mut arguments.empty_arguments {
+        v.visit_identifier_mut(&mut argument.name);
+    }
+}
+
+/// Like [`walk_argument()`], with mutable nodes.
+pub fn walk_argument_mut<V: VisitMut + ?Sized>(v: &mut V, argument: &mut Argument) {
+    if let Some(name) = &mut argument.name {
+        v.visit_identifier_mut(name);
+    }
+    v.visit_expression_mut(&mut argument.value);
+}
+
+/// Like [`walk_expression()`], with mutable nodes.
+pub fn walk_expression_mut<V: VisitMut + ?Sized>(v: &mut V, expression: &mut Expression) {
+    match expression {
+        Expression::Function(name, arguments, _) => {
+            v.visit_identifier_mut(name);
+            v.visit_arguments_list_mut(arguments);
+        }
+        Expression::Array(values, _) => {
+            for value in values {
+                v.visit_expression_mut(value);
+            }
+        }
+        Expression::NumericValue(..)
+        | Expression::StringValue(..)
+        | Expression::ConstantValue(..) => {}
+    }
+}
+
+/// Like [`walk_source_config()`], with mutable nodes.
+pub fn walk_source_config_mut<V: VisitMut + ?Sized>(v: &mut V, source: &mut SourceConfig) {
+    if let Some(comment) = &mut source.documentation {
+        v.visit_comment_mut(comment);
+    }
+    v.visit_identifier_mut(&mut source.name);
+    for property in &mut source.properties {
+        v.visit_config_block_property_mut(property);
+    }
+    for comment in &mut source.comments {
+        v.visit_comment_mut(comment);
+    }
+}
+
+/// Like [`walk_generator_config()`], with mutable nodes.
+pub fn walk_generator_config_mut<V: VisitMut + ?Sized>(v: &mut V, generator: &mut GeneratorConfig) {
+    if let Some(comment) = &mut generator.documentation {
+        v.visit_comment_mut(comment);
+    }
+    v.visit_identifier_mut(&mut generator.name);
+    for property in &mut generator.properties {
+        v.visit_config_block_property_mut(property);
+    }
+    for comment in &mut generator.comments {
+        v.visit_comment_mut(comment);
+    }
+}
+
+/// Like [`walk_config_block_property()`], with mutable nodes.
+pub fn walk_config_block_property_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    property: &mut ConfigBlockProperty,
+) {
+    if let Some(comment) = &mut property.documentation {
+        v.visit_comment_mut(comment);
+    }
+    v.visit_identifier_mut(&mut property.name);
+    if let Some(value) = &mut property.value {
+        v.visit_expression_mut(value);
+    }
+    if let Some(comment) = &mut property.trailing_comment {
+        v.visit_comment_mut(comment);
+    }
+}
+
+/// Like [`walk_invalid_block()`], with mutable nodes.
+pub fn walk_invalid_block_mut<V: VisitMut + ?Sized>(v: &mut V, invalid_block: &mut InvalidBlock) {
+    if let Some(name) = &mut invalid_block.name {
+        v.visit_identifier_mut(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnostics::Diagnostics, parse_schema};
+
+    const SCHEMA: &str = r#"
+datasource db {
+  provider = "postgres"
+  url      = env("DATABASE_URL") // from the environment
+}
+
+model User {
+  id    Int    @id @default(autoincrement())
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  author   User @relation(fields: [authorId], references: [id])
+  authorId Int
+}
+"#;
+
+    #[test]
+    fn visit_collects_identifiers_test() {
+        struct Collector {
+            identifiers: Vec<String>,
+            functions: Vec<String>,
+            comments: usize,
+        }
+
+        impl<'ast> Visit<'ast> for Collector {
+            fn visit_identifier(&mut self, identifier: &'ast Identifier) {
+                self.identifiers.push(identifier.name.clone());
+            }
+
+            fn visit_expression(&mut self, expression: &'ast Expression) {
+                if let Some((name, _, _)) = expression.as_function() {
+                    self.functions.push(name.to_owned());
+                }
+                walk_expression(self, expression);
+            }
+
+            fn visit_comment(&mut self, _comment: &'ast Comment) {
+                self.comments += 1;
+            }
+        }
+
+        let ast = parse_schema(SCHEMA, &mut Diagnostics::new());
+        let mut collector = Collector {
+            identifiers: Vec::new(),
+            functions: Vec::new(),
+            comments: 0,
+        };
+        collector.visit_schema(&ast);
+
+        assert_eq!(collector.functions, ["env", "autoincrement"]);
+        assert_eq!(collector.comments, 1);
+        assert_eq!(
+            &collector.identifiers[..6],
+            ["db", "provider", "url", "env", "User", "id"]
+        );
+        assert!(collector.identifiers.contains(&"fields".to_owned()));
+    }
+
+    #[test]
+    fn visit_mut_renames_model_test() {
+        struct Rename;
+
+        impl VisitMut for Rename {
+            fn visit_identifier_mut(&mut self, identifier: &mut Identifier) {
+                if identifier.name == "User" {
+                    identifier.name = "Account".to_owned();
+                }
+            }
+        }
+
+        let mut ast = parse_schema(SCHEMA, &mut Diagnostics::new());
+        Rename.visit_schema_mut(&mut ast);
+
+        let models: Vec<_> = ast.models().collect();
+        assert_eq!(models[0].name(), "Account");
+        assert!(matches!(
+            &models[1].fields[1].field_type,
+            FieldType::Supported(ident) if ident.name == "Account"
+        ));
+    }
+}
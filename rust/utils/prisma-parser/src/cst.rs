@@ -0,0 +1,223 @@
+use crate::ast::{NewlineType, Span};
+use std::fmt;
+
+/// A node of the concrete syntax tree. Its children cover its whole span,
+/// whitespace and comments included.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxNode {
+    /// What the node represents.
+    pub kind: NodeKind,
+    /// The location of this node in the text representation.
+    pub span: Span,
+    /// The nodes and tokens inside this node, in source order.
+    pub children: Vec<SyntaxElement>,
+}
+
+/// A node or a token.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyntaxElement {
+    /// An inner node.
+    Node(SyntaxNode),
+    /// A leaf token.
+    Token(SyntaxToken),
+}
+
+/// A handle to a node of a concrete syntax tree, that stays valid for every tree
+/// parsed from the same text. AST nodes give theirs with
+/// [`WithSyntax::syntax_ptr()`](crate::ast::WithSyntax::syntax_ptr).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxNodePtr {
+    /// The kind of the node.
+    pub kind: NodeKind,
+    /// The location of the node in the text representation.
+    pub span: Span,
+}
+
+/// A leaf of the concrete syntax tree, holding the exact source text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxToken {
+    /// What the token represents.
+    pub kind: TokenKind,
+    /// The source text of the token.
+    pub text: String,
+    /// The location of this token in the text representation.
+    pub span: Span,
+}
+
+/// The kinds of nodes in the concrete syntax tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    /// The whole schema.
+    Schema,
+    /// `model Foo { ... }`
+    Model,
+    /// `type Foo { ... }`
+    CompositeType,
+    /// `view Foo { ... }`
+    View,
+    /// `enum Foo { ... }`
+    Enum,
+    /// `datasource db { ... }`
+    Datasource,
+    /// `generator client { ... }`
+    Generator,
+    /// A block that could not be parsed.
+    InvalidBlock,
+    /// A line that could not be parsed.
+    InvalidLine,
+    /// `name String? @unique`
+    Field,
+    /// `String?`
+    FieldType,
+    /// `ADMIN @map("admin")`
+    EnumValue,
+    /// `provider = "postgres"`
+    Property,
+    /// `@@index([name])`
+    BlockAttribute,
+    /// `@default(now())`
+    FieldAttribute,
+    /// `(a, b: "c")`. Unnamed arguments are not wrapped in an `Argument` node.
+    ArgumentsList,
+    /// `map: "x"`
+    Argument,
+    /// `env("DATABASE_URL")`
+    Function,
+    /// `["a", "b"]`
+    Array,
+    /// Consecutive comment lines.
+    CommentBlock,
+}
+
+/// The kinds of tokens in the concrete syntax tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// Spaces and tabs.
+    Whitespace,
+    /// `\n`, `\r\n` or `\r`.
+    Newline,
+    /// `// ...`
+    Comment,
+    /// `/// ...`
+    DocComment,
+    /// `model`, `enum`, `datasource`, ...
+    Keyword,
+    /// A name or a dotted path like `db.VarChar`.
+    Identifier,
+    /// A string literal, quotes and escapes included.
+    String,
+    /// A numeric literal.
+    Number,
+    /// `{`, `}`, `(`, `)`, `[`, `]`, `,`, `:`, `=`, `@`, `@@`, `?`, `!` or `.`.
+    Punctuation,
+    /// Text that matches none of the above, in invalid lines or blocks.
+    Unknown,
+}
+
+impl SyntaxNode {
+    /// Iterate over the nodes directly inside this node.
+    pub fn child_nodes(&self) -> impl Iterator<Item = &SyntaxNode> {
+        self.children.iter().filter_map(|child| child.as_node())
+    }
+
+    /// Iterate over all the tokens inside this node, in source order.
+    pub fn tokens(&self) -> Box<dyn Iterator<Item = &SyntaxToken> + '_> {
+        Box::new(self.children.iter().flat_map(|child| match child {
+            SyntaxElement::Node(node) => node.tokens(),
+            SyntaxElement::Token(token) => Box::new(std::iter::once(token)),
+        }))
+    }
+
+    /// Find the innermost node with exactly the given span, e.g. to go from an
+    /// AST node to the source text around it.
+    pub fn find_node(&self, span: Span) -> Option<&SyntaxNode> {
+        if self.span.start > span.start || self.span.end < span.end {
+            return None;
+        }
+
+        self.child_nodes()
+            .find_map(|child| child.find_node(span))
+            .or_else(|| (self.span == span).then_some(self))
+    }
+}
+
+impl SyntaxNodePtr {
+    /// The handle of a node.
+    pub fn new(node: &SyntaxNode) -> Self {
+        SyntaxNodePtr {
+            kind: node.kind,
+            span: node.span,
+        }
+    }
+
+    /// Find the node in a tree, from its root.
+    pub fn to_node<'a>(&self, root: &'a SyntaxNode) -> Option<&'a SyntaxNode> {
+        if root.span.start > self.span.start || root.span.end < self.span.end {
+            return None;
+        }
+
+        if root.kind == self.kind && root.span == self.span {
+            return Some(root);
+        }
+
+        root.child_nodes().find_map(|child| self.to_node(child))
+    }
+}
+
+impl SyntaxElement {
+    /// The location of this element in the text representation.
+    pub fn span(&self) -> Span {
+        match self {
+            SyntaxElement::Node(node) => node.span,
+            SyntaxElement::Token(token) => token.span,
+        }
+    }
+
+    /// Try to interpret the element as a node.
+    pub fn as_node(&self) -> Option<&SyntaxNode> {
+        match self {
+            SyntaxElement::Node(node) => Some(node),
+            SyntaxElement::Token(_) => None,
+        }
+    }
+
+    /// Try to interpret the element as a token.
+    pub fn as_token(&self) -> Option<&SyntaxToken> {
+        match self {
+            SyntaxElement::Token(token) => Some(token),
+            SyntaxElement::Node(_) => None,
+        }
+    }
+}
+
+impl SyntaxToken {
+    /// Whitespace, newlines and comments.
+    pub fn is_trivia(&self) -> bool {
+        matches!(
+            self.kind,
+            TokenKind::Whitespace | TokenKind::Newline | TokenKind::Comment | TokenKind::DocComment
+        )
+    }
+
+    /// The newline type of a newline token. A lone `\r` has none.
+    pub fn newline_type(&self) -> Option<NewlineType> {
+        match (self.kind, self.text.as_str()) {
+            (TokenKind::Newline, "\n") => Some(NewlineType::Unix),
+            (TokenKind::Newline, "\r\n") => Some(NewlineType::Windows),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the exact source text back.
+impl fmt::Display for SyntaxNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.tokens().try_for_each(|token| f.write_str(&token.text))
+    }
+}
+
+impl fmt::Display for SyntaxToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
@@ -0,0 +1,362 @@
+use crate::{
+    ast::{IndentationType, NewlineType},
+    cst::{NodeKind, SyntaxElement, SyntaxNode, SyntaxToken, TokenKind},
+    diagnostics::Diagnostics,
+    parse_schema_with_cst,
+};
+
+/// How to lay out a formatted schema.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FormatOptions {
+    /// The indentation of block contents.
+    pub indentation: IndentationType,
+    /// The line endings of the output.
+    pub newline: NewlineType,
+}
+
+impl FormatOptions {
+    /// The indentation and line endings the schema already uses, so that
+    /// formatted or generated text matches the rest of the file.
+    pub fn detect(schema: &str) -> Self {
+        FormatOptions {
+            indentation: IndentationType::detect(schema),
+            newline: NewlineType::detect(schema),
+        }
+    }
+}
+
+/// Format a PSL string: block contents are indented with one level of
+/// `opts.indentation`, the `=` signs of config block properties and the types of
+/// consecutive fields are aligned, other runs of spaces are collapsed into one,
+/// consecutive blank lines are collapsed into one, and comments stay where they
+/// are, with one space before trailing comments.
+///
+/// Schemas with syntax errors are returned unchanged.
+pub fn format(schema: &str, opts: FormatOptions) -> String {
+    let mut diagnostics = Diagnostics::new();
+    let (_, cst) = parse_schema_with_cst(schema, &mut diagnostics);
+
+    if diagnostics.has_errors() {
+        return schema.to_owned();
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut previous_end = None;
+
+    for node in cst.child_nodes() {
+        push_separator(schema, previous_end, node, &mut lines);
+
+        match node.kind {
+            // A comment after a closing brace stays on its line.
+            NodeKind::CommentBlock
+                if previous_end.is_some_and(|end| same_line(schema, end, node)) =>
+            {
+                let mut comments = comment_lines(node, "");
+                lines
+                    .last_mut()
+                    .unwrap()
+                    .push_str(&format!(" {}", comments.remove(0)));
+                lines.extend(comments);
+            }
+            NodeKind::CommentBlock => lines.extend(comment_lines(node, "")),
+            _ => format_block(schema, node, &opts.indentation.to_string(), &mut lines),
+        }
+
+        previous_end = Some(node.span.end);
+    }
+
+    if lines.is_empty() {
+        return String::new();
+    }
+
+    let newline = opts.newline.as_ref();
+    let mut out = lines.join(newline);
+    out.push_str(newline);
+    out
+}
+
+fn format_block(schema: &str, block: &SyntaxNode, indent: &str, lines: &mut Vec<String>) {
+    let mut tokens = block.children.iter().filter_map(|child| child.as_token());
+    let keyword = tokens
+        .find(|token| token.kind == TokenKind::Keyword)
+        .unwrap();
+    let name = tokens
+        .find(|token| token.kind == TokenKind::Identifier)
+        .unwrap();
+    let mut header = format!("{} {} {{", keyword.text, name.text);
+
+    // A comment on the same line as the opening brace.
+    if let Some(comment) = tokens
+        .take_while(|token| token.kind != TokenKind::Newline)
+        .find(|token| matches!(token.kind, TokenKind::Comment | TokenKind::DocComment))
+    {
+        header.push(' ');
+        header.push_str(&comment.text);
+    }
+
+    lines.push(header);
+
+    let mut previous_end = None;
+    let mut properties: Vec<(String, String)> = Vec::new();
+    let mut fields: Vec<[String; 3]> = Vec::new();
+
+    for member in block.child_nodes() {
+        let blank_line = previous_end.is_some_and(|end| blank_lines_between(schema, end, member));
+
+        if blank_line || member.kind != NodeKind::Property {
+            flush_properties(&mut properties, indent, lines);
+        }
+
+        if blank_line || member.kind != NodeKind::Field {
+            flush_fields(&mut fields, indent, lines);
+        }
+
+        if blank_line {
+            lines.push(String::new());
+        }
+
+        match member.kind {
+            NodeKind::CommentBlock => lines.extend(comment_lines(member, indent)),
+            NodeKind::Property => properties.push(split_property(member)),
+            NodeKind::Field => fields.push(split_field(member)),
+            _ => lines.push(format!("{indent}{}", normalize(member.tokens()))),
+        }
+
+        previous_end = Some(member.span.end);
+    }
+
+    flush_properties(&mut properties, indent, lines);
+    flush_fields(&mut fields, indent, lines);
+    lines.push("}".to_owned());
+}
+
+/// Split a property into its name and the rest, from the value on.
+fn split_property(property: &SyntaxNode) -> (String, String) {
+    let name = property.tokens().next().unwrap().text.clone();
+    let rest = normalize(
+        property
+            .tokens()
+            .skip_while(|token| token.text != "=")
+            .skip(1),
+    );
+
+    (name, rest)
+}
+
+/// Split a field into its name, its type and the rest, from the attributes on.
+/// The type is empty when the field has none.
+fn split_field(field: &SyntaxNode) -> [String; 3] {
+    let type_idx = field
+        .children
+        .iter()
+        .position(|child| {
+            child
+                .as_node()
+                .is_some_and(|node| node.kind == NodeKind::FieldType)
+        })
+        .unwrap_or(field.children.len());
+    let tokens = |children: &[SyntaxElement]| -> String {
+        normalize(children.iter().flat_map(|child| match child {
+            SyntaxElement::Node(node) => node.tokens(),
+            SyntaxElement::Token(token) => Box::new(std::iter::once(token)),
+        }))
+    };
+
+    let (name, rest) = field.children.split_at(type_idx);
+    let (field_type, rest) = rest.split_at(rest.len().min(1));
+
+    [tokens(name), tokens(field_type), tokens(rest)]
+}
+
+/// Write the pending fields with their types and attributes aligned.
+fn flush_fields(fields: &mut Vec<[String; 3]>, indent: &str, lines: &mut Vec<String>) {
+    let width = |column: usize| {
+        fields
+            .iter()
+            .map(|field| field[column].chars().count())
+            .max()
+            .unwrap_or(0)
+    };
+    let (name_width, type_width) = (width(0), width(1));
+
+    for [name, field_type, rest] in fields.drain(..) {
+        let line = format!("{indent}{name:<name_width$} {field_type:<type_width$} {rest}");
+        lines.push(line.trim_end().to_owned());
+    }
+}
+
+/// The source text of the tokens, with line breaks removed and runs of spaces
+/// collapsed into one. There are no spaces inside brackets or before commas,
+/// and always one after commas and before a trailing comment.
+fn normalize<'a>(tokens: impl Iterator<Item = &'a SyntaxToken>) -> String {
+    let mut out = String::new();
+    let mut space = false;
+
+    for token in tokens {
+        match token.kind {
+            TokenKind::Whitespace | TokenKind::Newline => space = true,
+            kind => {
+                let is_comment = matches!(kind, TokenKind::Comment | TokenKind::DocComment);
+                let after_bracket = out.ends_with(['(', '[']);
+                let before_bracket = matches!(token.text.as_str(), ")" | "]" | ",");
+                let separate = (space || out.ends_with(',')) && !after_bracket && !before_bracket;
+
+                if !out.is_empty() && (is_comment || separate) {
+                    out.push(' ');
+                }
+
+                out.push_str(token.text.trim_end());
+                space = false;
+            }
+        }
+    }
+
+    out
+}
+
+/// Write the pending properties with their `=` signs aligned.
+fn flush_properties(properties: &mut Vec<(String, String)>, indent: &str, lines: &mut Vec<String>) {
+    let width = properties
+        .iter()
+        .map(|(name, _)| name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    for (name, rest) in properties.drain(..) {
+        let line = format!("{indent}{name:<width$} = {rest}");
+        lines.push(line.trim_end().to_owned());
+    }
+}
+
+fn comment_lines(comment_block: &SyntaxNode, indent: &str) -> Vec<String> {
+    comment_block
+        .tokens()
+        .filter(|token| matches!(token.kind, TokenKind::Comment | TokenKind::DocComment))
+        .map(|comment| format!("{indent}{}", comment.text.trim_end()))
+        .collect()
+}
+
+/// Keep at most one blank line between two items.
+fn push_separator(
+    schema: &str,
+    previous_end: Option<usize>,
+    node: &SyntaxNode,
+    lines: &mut Vec<String>,
+) {
+    if previous_end.is_some_and(|end| blank_lines_between(schema, end, node)) {
+        lines.push(String::new());
+    }
+}
+
+/// Does `node` start on the line where the previous node ends?
+fn same_line(schema: &str, end: usize, node: &SyntaxNode) -> bool {
+    !schema[end..node.span.start].contains(['\n', '\r'])
+}
+
+/// Is there at least one blank line between `end` and the start of `node`?
+/// Nodes may or may not include their own trailing newline.
+fn blank_lines_between(schema: &str, end: usize, node: &SyntaxNode) -> bool {
+    let previous = schema[..end].trim_end_matches([' ', '\t', '\r', '\n']);
+    let gap = &schema[previous.len()..node.span.start];
+
+    gap.matches('\n').count() > 1 || gap.matches('\r').count() > 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_schema_test() {
+        let schema = r#"
+
+// Our database.
+datasource db {
+      provider = "postgres"   // the provider
+  url = env("DATABASE_URL")
+
+
+  relationMode="prisma"
+}
+/// A user.
+model User { // users
+   id    Int     @id   @default( autoincrement() )// the id
+  email String   @unique
+
+
+    /// The name.
+  name String?
+  @@index([ email,name ])
+  @@map("users")
+} // end of users
+// Roles.
+
+
+
+enum Role {
+ADMIN    @map("admin")
+    USER
+}
+"#;
+        let expected = r#"// Our database.
+datasource db {
+  provider = "postgres" // the provider
+  url      = env("DATABASE_URL")
+
+  relationMode = "prisma"
+}
+/// A user.
+model User { // users
+  id    Int    @id @default(autoincrement()) // the id
+  email String @unique
+
+  /// The name.
+  name String?
+  @@index([email, name])
+  @@map("users")
+} // end of users
+// Roles.
+
+enum Role {
+  ADMIN @map("admin")
+  USER
+}
+"#;
+        let formatted = format(schema, FormatOptions::default());
+
+        assert_eq!(formatted, expected);
+        assert_eq!(format(&formatted, FormatOptions::default()), expected);
+    }
+
+    #[test]
+    fn format_with_options_test() {
+        let schema =
+            "generator client {\n  provider = \"prisma-client-js\"\n  output = \"../client\"\n}\n";
+        let opts = FormatOptions {
+            indentation: IndentationType::Tabs,
+            newline: NewlineType::Windows,
+        };
+
+        assert_eq!(
+            format(schema, opts),
+            "generator client {\r\n\tprovider = \"prisma-client-js\"\r\n\toutput   = \"../client\"\r\n}\r\n"
+        );
+    }
+
+    #[test]
+    fn format_with_detected_options_test() {
+        let schema = "model User {\r\n\tid Int @id\r\n\r\n\r\n\tname String\r\n}\r\n";
+
+        assert_eq!(
+            format(schema, FormatOptions::detect(schema)),
+            "model User {\r\n\tid Int @id\r\n\r\n\tname String\r\n}\r\n"
+        );
+    }
+
+    #[test]
+    fn format_keeps_invalid_schemas_test() {
+        let schema = "model User {\n    id Int\n";
+
+        assert_eq!(format(schema, FormatOptions::default()), schema);
+    }
+}
@@ -2,16 +2,29 @@
 
 #![allow(clippy::derive_partial_eq_without_eq)]
 
-pub use self::parser::parse_schema;
+pub use self::{
+    format::{format, FormatOptions},
+    parser::{
+        parse_cst, parse_schema, parse_schema_with_cst, parse_schemas, reparse_schema, TextEdit,
+    },
+    semantic_tokens::{encode_semantic_tokens, semantic_tokens, SemanticToken, SemanticTokenType},
+};
 
 /// The AST data structure. It aims to faithfully represent the syntax of a Prisma Schema, with
 /// source span information.
 pub mod ast;
 
+/// The lossless concrete syntax tree. It keeps whitespace, comments and the exact
+/// source text, so that tools can edit a schema without reformatting it. The
+/// [AST](ast) nodes point into it, see [`parse_schema_with_cst()`] to get both.
+pub mod cst;
+
 /// The validation or parser errors and warnings
 pub(crate) use prisma_diagnostics as diagnostics;
 
+mod format;
 mod parser;
+mod semantic_tokens;
 
 /// Transform the input string into a valid (quoted and escaped) PSL string literal.
 ///
@@ -19,7 +32,7 @@ mod parser;
 /// literals](https://datatracker.ietf.org/doc/html/rfc8259#section-7).
 ///
 /// ```
-/// # use schema_ast::string_literal;
+/// # use prisma_parser::string_literal;
 /// let input = r#"oh
 /// hi"#;
 /// assert_eq!(r#""oh\nhi""#, &string_literal(input).to_string());
@@ -1,11 +1,21 @@
 mod helpers;
+mod map_spans;
 mod parse_arguments;
+mod parse_attribute;
 mod parse_comments;
+mod parse_cst;
+mod parse_enum;
 mod parse_expression;
+mod parse_field;
+mod parse_model;
 mod parse_schema;
 mod parse_source_and_generator;
+mod parse_types;
+mod reparse;
 
-pub use parse_schema::parse_schema;
+pub use parse_cst::{parse_cst, parse_schema_with_cst};
+pub use parse_schema::{parse_schema, parse_schemas};
+pub use reparse::{reparse_schema, TextEdit};
 
 // The derive is placed here because it generates the `Rule` enum which is used in all parsing functions.
 // It is more convenient if this enum is directly available here.
@@ -2,6 +2,9 @@ use super::Rule;
 
 pub type Pair<'a> = pest::iterators::Pair<'a, Rule>;
 
+/// The result of parsing a whole schema with pest, before it is turned into a tree.
+pub type SchemaParseResult<'a> = Result<pest::iterators::Pairs<'a, Rule>, pest::error::Error<Rule>>;
+
 #[track_caller]
 pub fn parsing_catch_all(token: &Pair<'_>, kind: &str) {
     match token.as_rule() {
@@ -0,0 +1,100 @@
+use crate::ast::{visit::*, *};
+
+/// Applies a function to every span in the visited nodes, e.g. to move them
+/// after an edit.
+pub(crate) struct MapSpans<F>(pub(crate) F);
+
+impl<F: FnMut(Span) -> Span> VisitMut for MapSpans<F> {
+    fn visit_model_mut(&mut self, model: &mut Model) {
+        model.span = (self.0)(model.span);
+        model.inner_span = (self.0)(model.inner_span);
+        walk_model_mut(self, model);
+    }
+
+    fn visit_composite_type_mut(&mut self, composite_type: &mut CompositeType) {
+        composite_type.span = (self.0)(composite_type.span);
+        composite_type.inner_span = (self.0)(composite_type.inner_span);
+        walk_composite_type_mut(self, composite_type);
+    }
+
+    fn visit_view_mut(&mut self, view: &mut View) {
+        view.span = (self.0)(view.span);
+        view.inner_span = (self.0)(view.inner_span);
+        walk_view_mut(self, view);
+    }
+
+    fn visit_field_mut(&mut self, field: &mut Field) {
+        field.span = (self.0)(field.span);
+        if let FieldType::Unsupported(_, span) = &mut field.field_type {
+            *span = (self.0)(*span);
+        }
+        walk_field_mut(self, field);
+    }
+
+    fn visit_enum_mut(&mut self, r#enum: &mut Enum) {
+        r#enum.span = (self.0)(r#enum.span);
+        r#enum.inner_span = (self.0)(r#enum.inner_span);
+        walk_enum_mut(self, r#enum);
+    }
+
+    fn visit_enum_value_mut(&mut self, value: &mut EnumValue) {
+        value.span = (self.0)(value.span);
+        walk_enum_value_mut(self, value);
+    }
+
+    fn visit_attribute_mut(&mut self, attribute: &mut Attribute) {
+        attribute.span = (self.0)(attribute.span);
+        walk_attribute_mut(self, attribute);
+    }
+
+    fn visit_arguments_list_mut(&mut self, arguments: &mut ArgumentsList) {
+        arguments.trailing_comma = arguments.trailing_comma.map(&mut self.0);
+        walk_arguments_list_mut(self, arguments);
+    }
+
+    fn visit_argument_mut(&mut self, argument: &mut Argument) {
+        argument.span = (self.0)(argument.span);
+        walk_argument_mut(self, argument);
+    }
+
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        match expression {
+            Expression::NumericValue(_, span)
+            | Expression::StringValue(_, span)
+            | Expression::ConstantValue(_, span)
+            | Expression::Function(_, _, span)
+            | Expression::Array(_, span) => *span = (self.0)(*span),
+        }
+        walk_expression_mut(self, expression);
+    }
+
+    fn visit_source_config_mut(&mut self, source: &mut SourceConfig) {
+        source.span = (self.0)(source.span);
+        source.inner_span = (self.0)(source.inner_span);
+        walk_source_config_mut(self, source);
+    }
+
+    fn visit_generator_config_mut(&mut self, generator: &mut GeneratorConfig) {
+        generator.span = (self.0)(generator.span);
+        generator.inner_span = (self.0)(generator.inner_span);
+        walk_generator_config_mut(self, generator);
+    }
+
+    fn visit_config_block_property_mut(&mut self, property: &mut ConfigBlockProperty) {
+        property.span = (self.0)(property.span);
+        walk_config_block_property_mut(self, property);
+    }
+
+    fn visit_invalid_block_mut(&mut self, invalid_block: &mut InvalidBlock) {
+        invalid_block.span = (self.0)(invalid_block.span);
+        walk_invalid_block_mut(self, invalid_block);
+    }
+
+    fn visit_identifier_mut(&mut self, identifier: &mut Identifier) {
+        identifier.span = (self.0)(identifier.span);
+    }
+
+    fn visit_comment_mut(&mut self, comment: &mut Comment) {
+        comment.span = (self.0)(comment.span);
+    }
+}
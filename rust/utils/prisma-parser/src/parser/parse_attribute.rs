@@ -0,0 +1,78 @@
+use super::{
+    helpers::{parsing_catch_all, Pair},
+    parse_arguments::parse_arguments_list,
+    Rule,
+};
+use crate::{ast::*, diagnostics::Diagnostics};
+
+pub(crate) fn parse_attribute(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Attribute {
+    let span = Span::from(pair.as_span());
+    let mut name = None;
+    let mut arguments: ArgumentsList = ArgumentsList::default();
+
+    for current in pair.into_inner() {
+        match current.as_rule() {
+            Rule::path => name = Some(current.into()),
+            Rule::arguments_list => parse_arguments_list(current, &mut arguments, diagnostics),
+            _ => parsing_catch_all(&current, "attribute"),
+        }
+    }
+
+    let name = name.unwrap();
+    Attribute {
+        name,
+        arguments,
+        span,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ast::*, diagnostics::Diagnostics, parse_schema};
+
+    #[test]
+    fn parse_attribute_arguments_test() {
+        let schema = r#"model Post {
+  id     Int    @id
+  author String @relation(name: "author", onDelete: )
+  title  String @map("post_title", )
+
+  @@map(name: "posts")
+}
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{diagnostics:?}");
+
+        let model = ast.models().next().unwrap();
+        assert_eq!(model.fields[0].attributes[0].name(), "id");
+
+        let relation = &model.fields[1].attributes[0];
+        assert_eq!(relation.name(), "relation");
+        assert_eq!(
+            relation.name.span,
+            Span::new(
+                schema.find("relation").unwrap(),
+                schema.find("relation").unwrap() + "relation".len()
+            )
+        );
+        assert!(relation.span_for_argument("name").is_some());
+        assert_eq!(relation.arguments.empty_arguments[0].name.name, "onDelete");
+
+        let map = &model.fields[2].attributes[0];
+        assert!(map.arguments.arguments[0].is_unnamed());
+        assert!(map.arguments.trailing_comma.is_some());
+
+        assert_eq!(model.attributes[0].name(), "map");
+        assert!(model.attributes[0].span_for_argument("name").is_some());
+
+        let position = schema.find("onDelete").unwrap() + 1;
+        assert!(matches!(
+            ast.find_at_position(position),
+            SchemaPosition::Model(
+                _,
+                ModelPosition::Field(_, FieldPosition::Attribute("relation", 0, Some("onDelete")))
+            )
+        ));
+    }
+}
@@ -2,7 +2,7 @@ use super::{
     helpers::{parsing_catch_all, Pair},
     Rule,
 };
-use crate::ast::Comment;
+use crate::ast::{Comment, Span};
 
 pub(crate) fn parse_comment_block(token: Pair<'_>) -> Option<Comment> {
     debug_assert!(token.as_rule() == Rule::comment_block);
@@ -20,10 +20,127 @@ pub(crate) fn parse_comment_block(token: Pair<'_>) -> Option<Comment> {
     } else {
         Some(Comment {
             text: lines.join("\n"),
+            is_doc: true,
+            span: comment_span(&token),
         })
     }
 }
 
+/// Every line of a comment block as its own comment. With `regular_only`, the
+/// doc comment lines are skipped.
+pub(crate) fn parse_comment_lines(token: &Pair<'_>, regular_only: bool) -> Vec<Comment> {
+    debug_assert!(token.as_rule() == Rule::comment_block);
+    let mut comments = Vec::new();
+    for comment in token.clone().into_inner() {
+        match comment.as_rule() {
+            Rule::doc_comment if regular_only => {}
+            Rule::doc_comment | Rule::comment => comments.push(parse_comment_line(comment)),
+            Rule::NEWLINE | Rule::WHITESPACE => {}
+            _ => parsing_catch_all(&comment, "comment block"),
+        }
+    }
+    comments
+}
+
+fn parse_comment_line(pair: Pair<'_>) -> Comment {
+    Comment {
+        is_doc: pair.as_rule() == Rule::doc_comment,
+        span: comment_span(&pair),
+        text: parse_doc_comment(pair).to_owned(),
+    }
+}
+
+/// The span of a comment pair, without its surrounding whitespace and newlines.
+fn comment_span(pair: &Pair<'_>) -> Span {
+    let text = pair.as_str();
+    let start = pair.as_span().start() + (text.len() - text.trim_start().len());
+
+    Span::new(start, start + text.trim().len())
+}
+
+pub(crate) fn parse_trailing_comment(pair: Pair<'_>) -> Option<Comment> {
+    debug_assert_eq!(pair.as_rule(), Rule::trailing_comment);
+    let mut lines = Vec::new();
+    for comment in pair.clone().into_inner() {
+        match comment.as_rule() {
+            Rule::doc_comment => lines.push(parse_doc_comment(comment)),
+            Rule::comment | Rule::NEWLINE | Rule::WHITESPACE => {}
+            _ => parsing_catch_all(&comment, "trailing comment"),
+        }
+    }
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(Comment {
+            text: lines.join("\n"),
+            is_doc: true,
+            span: comment_span(&pair),
+        })
+    }
+}
+
+/// A trailing comment, doc comment or not.
+pub(crate) fn parse_trailing_comment_line(pair: Pair<'_>) -> Option<Comment> {
+    debug_assert_eq!(pair.as_rule(), Rule::trailing_comment);
+    pair.into_inner()
+        .find(|comment| matches!(comment.as_rule(), Rule::doc_comment | Rule::comment))
+        .map(parse_comment_line)
+}
+
+/// The comment blocks inside a block. The doc comments right above a member
+/// document it, the other comments float.
+#[derive(Default)]
+pub(crate) struct BlockComments<'a> {
+    pending: Option<Pair<'a>>,
+    floating: Vec<Comment>,
+}
+
+impl<'a> BlockComments<'a> {
+    pub(crate) fn push(&mut self, comment_block: Pair<'a>) {
+        self.floating
+            .extend(parse_comment_lines(&comment_block, true));
+
+        if let Some(previous) = self.pending.replace(comment_block) {
+            self.float_doc_comments(&previous);
+        }
+    }
+
+    /// The comment block above the next member, for its documentation.
+    pub(crate) fn take_pending(&mut self) -> Option<Pair<'a>> {
+        self.pending.take()
+    }
+
+    /// The floating comments, in source order. Doc comments that document no
+    /// member float too.
+    pub(crate) fn finish(mut self) -> Vec<Comment> {
+        if let Some(pending) = self.pending.take() {
+            self.float_doc_comments(&pending);
+        }
+
+        self.floating.sort_by_key(|comment| comment.span.start);
+        self.floating
+    }
+
+    fn float_doc_comments(&mut self, comment_block: &Pair<'a>) {
+        let comments = parse_comment_lines(comment_block, false);
+        self.floating
+            .extend(comments.into_iter().filter(|comment| comment.is_doc));
+    }
+}
+
+/// Append a trailing doc comment to the documentation above a node.
+pub(crate) fn merge_comments(existing: Option<Comment>, new: Option<Comment>) -> Option<Comment> {
+    match (existing, new) {
+        (c, None) | (None, c) => c,
+        (Some(existing), Some(new)) => Some(Comment {
+            text: [existing.text, new.text].join("\n"),
+            is_doc: true,
+            span: Span::new(existing.span.start, new.span.end),
+        }),
+    }
+}
+
 pub(crate) fn parse_doc_comment(token: Pair<'_>) -> &str {
     let child = token.into_inner().next().unwrap();
     match child.as_rule() {
@@ -0,0 +1,357 @@
+use super::{
+    helpers::{Pair, SchemaParseResult},
+    parse_schema::build_schema_ast,
+    PrismaSchemaParser, Rule,
+};
+use crate::ast::{SchemaAst, Span};
+use crate::cst::*;
+use crate::diagnostics::Diagnostics;
+use pest::Parser;
+
+/// Parse a PSL string into a lossless concrete syntax tree: writing the tree back
+/// with `to_string()` returns the input byte for byte.
+pub fn parse_cst(schema_string: &str) -> SyntaxNode {
+    build_cst(
+        schema_string,
+        PrismaSchemaParser::parse(Rule::schema, schema_string),
+    )
+}
+
+/// Parse a PSL string into both its AST and its concrete syntax tree, from a
+/// single run of the parser.
+///
+/// The AST is a typed view over the concrete syntax tree: its blocks, fields,
+/// enum values and properties hold the handle of their concrete node, see
+/// [`WithSyntax::syntax_ptr()`](crate::ast::WithSyntax::syntax_ptr). Tools go from
+/// an AST node to its exact text, and edit it without reformatting the rest of the
+/// schema. [`reparse_schema()`](crate::reparse_schema) then updates the AST.
+///
+/// ```
+/// use prisma_diagnostics::Diagnostics;
+/// use prisma_parser::{ast::WithSyntax, cst::TokenKind, parse_schema_with_cst, TextEdit};
+///
+/// let schema = "datasource db {\n  provider   =  \"postgres\" // keep me\n}\n";
+/// let (ast, cst) = parse_schema_with_cst(schema, &mut Diagnostics::new());
+/// let provider = &ast.sources().next().unwrap().properties[0];
+///
+/// // Replace the string of the property, and nothing else.
+/// let node = provider.syntax_ptr().to_node(&cst).unwrap();
+/// let value = node.tokens().find(|token| token.kind == TokenKind::String).unwrap();
+/// let edit = TextEdit {
+///     span: value.span,
+///     new_text: "\"mysql\"".to_owned(),
+/// };
+///
+/// assert_eq!(
+///     TextEdit::apply(schema, &[edit]),
+///     "datasource db {\n  provider   =  \"mysql\" // keep me\n}\n"
+/// );
+/// ```
+pub fn parse_schema_with_cst(
+    schema_string: &str,
+    diagnostics: &mut Diagnostics,
+) -> (SchemaAst, SyntaxNode) {
+    let schema_result = PrismaSchemaParser::parse(Rule::schema, schema_string);
+    let ast = build_schema_ast(schema_string, schema_result.clone(), diagnostics);
+
+    (ast, build_cst(schema_string, schema_result))
+}
+
+fn build_cst(schema_string: &str, schema_result: SchemaParseResult<'_>) -> SyntaxNode {
+    let span = Span::new(0, schema_string.len());
+    let mut children = Vec::new();
+
+    match schema_result {
+        Ok(mut schema_wrapped) => {
+            let schema = schema_wrapped.next().unwrap();
+            let (start, end) = (schema.as_span().start(), schema.as_span().end());
+
+            lex(schema_string, 0, start, &mut children);
+            walk(schema_string, schema, &mut children);
+            lex(schema_string, end, schema_string.len(), &mut children);
+        }
+        // The schema still round-trips, as a single invalid line.
+        Err(_) => children.push(SyntaxElement::Node(SyntaxNode {
+            kind: NodeKind::InvalidLine,
+            span,
+            children: lexed(schema_string, span),
+        })),
+    }
+
+    SyntaxNode {
+        kind: NodeKind::Schema,
+        span,
+        children,
+    }
+}
+
+fn build(input: &str, pair: Pair<'_>, out: &mut Vec<SyntaxElement>) {
+    let span = Span::from(pair.as_span());
+
+    let token_kind = match pair.as_rule() {
+        Rule::identifier | Rule::path => Some(TokenKind::Identifier),
+        Rule::string_literal => Some(TokenKind::String),
+        Rule::numeric_literal => Some(TokenKind::Number),
+        Rule::MODEL_KEYWORD
+        | Rule::TYPE_KEYWORD
+        | Rule::VIEW_KEYWORD
+        | Rule::ENUM_KEYWORD
+        | Rule::DATASOURCE_KEYWORD
+        | Rule::GENERATOR_KEYWORD => Some(TokenKind::Keyword),
+        _ => None,
+    };
+
+    if let Some(kind) = token_kind {
+        out.push(SyntaxElement::Token(SyntaxToken {
+            kind,
+            text: pair.as_str().to_owned(),
+            span,
+        }));
+        return;
+    }
+
+    let node_kind = match pair.as_rule() {
+        Rule::model_declaration => match pair.clone().into_inner().next().map(|kw| kw.as_rule()) {
+            Some(Rule::TYPE_KEYWORD) => NodeKind::CompositeType,
+            Some(Rule::VIEW_KEYWORD) => NodeKind::View,
+            _ => NodeKind::Model,
+        },
+        Rule::config_block => match pair.clone().into_inner().next().map(|kw| kw.as_rule()) {
+            Some(Rule::GENERATOR_KEYWORD) => NodeKind::Generator,
+            _ => NodeKind::Datasource,
+        },
+        Rule::enum_declaration => NodeKind::Enum,
+        Rule::field_declaration => NodeKind::Field,
+        Rule::field_type => NodeKind::FieldType,
+        Rule::enum_value_declaration => NodeKind::EnumValue,
+        Rule::key_value => NodeKind::Property,
+        Rule::block_attribute => NodeKind::BlockAttribute,
+        Rule::field_attribute => NodeKind::FieldAttribute,
+        Rule::arguments_list => NodeKind::ArgumentsList,
+        Rule::named_argument | Rule::empty_argument => NodeKind::Argument,
+        Rule::function_call => NodeKind::Function,
+        Rule::array_expression => NodeKind::Array,
+        // Comments are lexed, so that their leading whitespace becomes trivia.
+        Rule::comment_block => return push_lexed_node(input, NodeKind::CommentBlock, span, out),
+        Rule::trailing_comment | Rule::comment | Rule::doc_comment => {
+            return lex(input, span.start, span.end, out)
+        }
+        Rule::broken_block | Rule::arbitrary_block => {
+            return push_lexed_node(input, NodeKind::InvalidBlock, span, out)
+        }
+        Rule::CATCH_ALL | Rule::BLOCK_LEVEL_CATCH_ALL => {
+            return push_lexed_node(input, NodeKind::InvalidLine, span, out)
+        }
+        // Block contents, expressions, type wrappers and punctuation rules are
+        // flattened into their parent.
+        _ => return walk(input, pair, out),
+    };
+
+    let mut children = Vec::new();
+    walk(input, pair, &mut children);
+    out.push(SyntaxElement::Node(SyntaxNode {
+        kind: node_kind,
+        span,
+        children,
+    }));
+}
+
+/// Build the children of a pair, lexing the text between them.
+fn walk(input: &str, pair: Pair<'_>, out: &mut Vec<SyntaxElement>) {
+    let (mut cursor, end) = (pair.as_span().start(), pair.as_span().end());
+
+    for child in pair.into_inner() {
+        let child_span = child.as_span();
+        lex(input, cursor, child_span.start(), out);
+        build(input, child, out);
+        cursor = child_span.end();
+    }
+
+    lex(input, cursor, end, out);
+}
+
+fn push_lexed_node(input: &str, kind: NodeKind, span: Span, out: &mut Vec<SyntaxElement>) {
+    out.push(SyntaxElement::Node(SyntaxNode {
+        kind,
+        span,
+        children: lexed(input, span),
+    }));
+}
+
+fn lexed(input: &str, span: Span) -> Vec<SyntaxElement> {
+    let mut out = Vec::new();
+    lex(input, span.start, span.end, &mut out);
+    out
+}
+
+/// Split text the grammar does not capture in its own rules into tokens:
+/// whitespace, newlines, comments and punctuation.
+fn lex(input: &str, start: usize, end: usize, out: &mut Vec<SyntaxElement>) {
+    let text = &input[start..end];
+    let mut offset = 0;
+
+    while offset < text.len() {
+        let rest = &text[offset..];
+        let first = rest.chars().next().unwrap();
+
+        let (kind, len) = if rest.starts_with("\r\n") {
+            (TokenKind::Newline, 2)
+        } else if first == '\n' || first == '\r' {
+            (TokenKind::Newline, 1)
+        } else if is_whitespace(first) {
+            (TokenKind::Whitespace, run_len(rest, is_whitespace))
+        } else if rest.starts_with("///") {
+            (
+                TokenKind::DocComment,
+                run_len(rest, |c| c != '\n' && c != '\r'),
+            )
+        } else if rest.starts_with("//") {
+            (
+                TokenKind::Comment,
+                run_len(rest, |c| c != '\n' && c != '\r'),
+            )
+        } else if rest.starts_with("@@") {
+            (TokenKind::Punctuation, 2)
+        } else if is_punctuation(first) {
+            (TokenKind::Punctuation, 1)
+        } else if is_identifier_char(first) {
+            (TokenKind::Identifier, run_len(rest, is_identifier_char))
+        } else {
+            let len = run_len(rest, |c| {
+                !(is_whitespace(c) || c == '\n' || c == '\r' || is_punctuation(c))
+            });
+            (TokenKind::Unknown, len)
+        };
+
+        out.push(SyntaxElement::Token(SyntaxToken {
+            kind,
+            text: rest[..len].to_owned(),
+            span: Span::new(start + offset, start + offset + len),
+        }));
+        offset += len;
+    }
+}
+
+fn run_len(text: &str, predicate: impl Fn(char) -> bool) -> usize {
+    text.find(|c| !predicate(c)).unwrap_or(text.len())
+}
+
+fn is_whitespace(c: char) -> bool {
+    c.is_whitespace() && c != '\n' && c != '\r'
+}
+
+fn is_punctuation(c: char) -> bool {
+    matches!(
+        c,
+        '{' | '}' | '(' | ')' | '[' | ']' | ',' | ':' | '=' | '@' | '?' | '!' | '.'
+    )
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_cst, parse_schema_with_cst};
+    use crate::{
+        ast::{Model, NewlineType, Top, WithSyntax},
+        cst::*,
+        diagnostics::Diagnostics,
+    };
+
+    #[test]
+    fn cst_round_trips_test() {
+        let schema = "// Free floating comment\r\n\r\ndatasource db {\r\n\tprovider = \"postgres\" // trailing\r\n\turl      = env(\"DATABASE_URL\")\r\n}\r\n\n/// A user.\nmodel User {\n  id    Int     @id @default(autoincrement())\n  email String? @unique @db.VarChar(200)\n  tags  String[]\n  geo   Unsupported(\"point\")\n  ü nope\n\n  @@index([email], map: \"idx\",)\n}\n\nmodel {\n}\nenum Role {\n  USER\n  ADMIN @map(\"admin\")\n   ";
+        let cst = parse_cst(schema);
+
+        assert_eq!(cst.to_string(), schema);
+
+        // Tokens are contiguous and their text matches their span.
+        let mut cursor = 0;
+        for token in cst.tokens() {
+            assert_eq!(token.span.start, cursor, "{token:?}");
+            assert_eq!(&schema[token.span.start..token.span.end], token.text);
+            cursor = token.span.end;
+        }
+        assert_eq!(cursor, schema.len());
+
+        let kinds: Vec<_> = cst.child_nodes().map(|node| node.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                NodeKind::CommentBlock,
+                NodeKind::Datasource,
+                NodeKind::CommentBlock,
+                NodeKind::Model,
+                NodeKind::InvalidBlock,
+                NodeKind::Enum,
+            ]
+        );
+
+        let newlines: Vec<_> = cst
+            .tokens()
+            .filter(|token| token.kind == TokenKind::Newline)
+            .map(|token| token.newline_type())
+            .take(2)
+            .collect();
+        assert_eq!(newlines, [Some(NewlineType::Windows); 2]);
+    }
+
+    #[test]
+    fn cst_find_node_from_ast_span_test() {
+        let schema = "datasource db {\n  provider = \"postgres\" // trailing\n}\n";
+        let ast = crate::parse_schema(schema, &mut crate::diagnostics::Diagnostics::new());
+        let cst = parse_cst(schema);
+
+        let property = &ast.sources().next().unwrap().properties[0];
+        let node = cst.find_node(property.span).unwrap();
+
+        assert_eq!(node.kind, NodeKind::Property);
+        assert_eq!(node.to_string(), "provider = \"postgres\" // trailing");
+        assert!(node.tokens().last().unwrap().kind == TokenKind::Comment);
+    }
+
+    #[test]
+    fn ast_nodes_map_to_cst_nodes_test() {
+        let schema = "datasource db {\n  provider = \"postgres\"\n}\n\ngenerator client {\n  provider = \"prisma-client-js\"\n}\n\nmodel User {\n  id Int @id\n}\n\ntype Address {\n  street String\n}\n\nview Admin {\n  id Int\n}\n\nenum Role {\n  USER\n}\n\nmodel {\n}\n";
+        let mut diagnostics = Diagnostics::new();
+        let (ast, cst) = parse_schema_with_cst(schema, &mut diagnostics);
+
+        assert_eq!(cst, parse_cst(schema));
+
+        let mut kinds = Vec::new();
+        for top in &ast.tops {
+            let member = match top {
+                Top::Source(source) => Some(source.properties[0].syntax_ptr()),
+                Top::Generator(generator) => Some(generator.properties[0].syntax_ptr()),
+                Top::Model(model) => Some(model.fields[0].syntax_ptr()),
+                Top::CompositeType(composite_type) => Some(composite_type.fields[0].syntax_ptr()),
+                Top::View(view) => Some(view.fields[0].syntax_ptr()),
+                Top::Enum(r#enum) => Some(r#enum.values[0].syntax_ptr()),
+                Top::Invalid(_) => None,
+            };
+
+            let node = top.syntax_ptr().to_node(&cst).unwrap();
+            let member = member.map(|member| member.to_node(node).unwrap().kind);
+            kinds.push((node.kind, member));
+        }
+
+        assert_eq!(
+            kinds,
+            [
+                (NodeKind::Datasource, Some(NodeKind::Property)),
+                (NodeKind::Generator, Some(NodeKind::Property)),
+                (NodeKind::Model, Some(NodeKind::Field)),
+                (NodeKind::CompositeType, Some(NodeKind::Field)),
+                (NodeKind::View, Some(NodeKind::Field)),
+                (NodeKind::Enum, Some(NodeKind::EnumValue)),
+                (NodeKind::InvalidBlock, None),
+            ]
+        );
+
+        // Built nodes have no text.
+        let built = Model::builder("Post").build();
+        assert_eq!(built.syntax_ptr().to_node(&cst), None);
+    }
+}
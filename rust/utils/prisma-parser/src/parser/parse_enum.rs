@@ -0,0 +1,143 @@
+use super::{
+    helpers::{parsing_catch_all, Pair},
+    parse_attribute::parse_attribute,
+    parse_comments::*,
+    Rule,
+};
+use crate::ast::*;
+use crate::diagnostics::{Diagnostics, SchemaError};
+
+pub(crate) fn parse_enum(
+    pair: Pair<'_>,
+    doc_comment: Option<Pair<'_>>,
+    diagnostics: &mut Diagnostics,
+) -> Enum {
+    let comment: Option<Comment> = doc_comment.and_then(parse_comment_block);
+    let pair_span = pair.as_span();
+    let mut name: Option<Identifier> = None;
+    let mut attributes: Vec<Attribute> = vec![];
+    let mut values: Vec<EnumValue> = vec![];
+    let mut comments = BlockComments::default();
+    let mut inner_span: Option<Span> = None;
+
+    for current in pair.into_inner() {
+        match current.as_rule() {
+            Rule::BLOCK_OPEN | Rule::BLOCK_CLOSE | Rule::ENUM_KEYWORD => {}
+            Rule::identifier => name = Some(current.into()),
+            Rule::enum_contents => {
+                inner_span = Some(current.as_span().into());
+
+                for item in current.into_inner() {
+                    match item.as_rule() {
+                        Rule::block_attribute => {
+                            attributes.push(parse_attribute(item, diagnostics))
+                        }
+                        Rule::enum_value_declaration => values.push(parse_enum_value(
+                            item,
+                            comments.take_pending(),
+                            diagnostics,
+                        )),
+                        Rule::comment_block => comments.push(item),
+                        Rule::BLOCK_LEVEL_CATCH_ALL => {
+                            diagnostics.push_error(SchemaError::new_validation_error(
+                                "This line is not an enum value definition.",
+                                item.as_span().into(),
+                            ))
+                        }
+                        _ => parsing_catch_all(&item, "enum"),
+                    }
+                }
+            }
+            _ => parsing_catch_all(&current, "enum"),
+        }
+    }
+
+    match name {
+        Some(name) => Enum {
+            name,
+            values,
+            attributes,
+            documentation: comment,
+            comments: comments.finish(),
+            span: Span::from(pair_span),
+            inner_span: inner_span.unwrap(),
+        },
+        _ => panic!("Encountered impossible enum declaration during parsing, name is missing."),
+    }
+}
+
+fn parse_enum_value(
+    pair: Pair<'_>,
+    doc_comment: Option<Pair<'_>>,
+    diagnostics: &mut Diagnostics,
+) -> EnumValue {
+    let (pair_str, pair_span) = (pair.as_str(), pair.as_span());
+    let mut name: Option<Identifier> = None;
+    let mut attributes: Vec<Attribute> = vec![];
+    let mut comment: Option<Comment> = doc_comment.and_then(parse_comment_block);
+    let mut trailing_comment: Option<Comment> = None;
+
+    for current in pair.into_inner() {
+        match current.as_rule() {
+            Rule::identifier => name = Some(current.into()),
+            Rule::field_attribute => attributes.push(parse_attribute(current, diagnostics)),
+            Rule::trailing_comment => match parse_trailing_comment(current.clone()) {
+                Some(doc_comment) => comment = merge_comments(comment, Some(doc_comment)),
+                None => trailing_comment = parse_trailing_comment_line(current),
+            },
+            _ => parsing_catch_all(&current, "enum value"),
+        }
+    }
+
+    match name {
+        Some(name) => EnumValue {
+            name,
+            attributes,
+            documentation: comment,
+            trailing_comment,
+            span: Span::from(pair_span),
+        },
+        _ => panic!(
+            "Encountered impossible enum value declaration during parsing, name is missing: {pair_str:?}",
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ast::*, diagnostics::Diagnostics, parse_schema};
+
+    #[test]
+    fn parse_enum_test() {
+        let schema = r#"/// User roles.
+enum Role {
+  USER
+  /// Can do anything.
+  ADMIN @map("admin")
+
+  @@map("roles")
+}
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{diagnostics:?}");
+
+        let r#enum = ast.enums().next().unwrap();
+        assert_eq!(r#enum.documentation(), Some("User roles."));
+        assert_eq!(r#enum.attributes[0].name(), "map");
+
+        let values: Vec<_> = r#enum.values.iter().map(|value| value.name()).collect();
+        assert_eq!(values, ["USER", "ADMIN"]);
+        assert_eq!(r#enum.values[1].documentation(), Some("Can do anything."));
+        assert_eq!(r#enum.values[1].attributes[0].name(), "map");
+
+        let position = schema.find("admin").unwrap();
+        assert!(matches!(
+            ast.find_at_position(position),
+            SchemaPosition::Enum(
+                _,
+                EnumPosition::Value("ADMIN", EnumValuePosition::Attribute("map", 0, None))
+            )
+        ));
+    }
+}
@@ -10,10 +10,13 @@ pub(crate) fn parse_expression(token: Pair<'_>, diagnostics: &mut Diagnostics) -
     let first_child = token.into_inner().next().unwrap();
     let span = Span::from(first_child.as_span());
     match first_child.as_rule() {
+        Rule::numeric_literal => Expression::NumericValue(first_child.as_str().to_string(), span),
         Rule::string_literal => {
             Expression::StringValue(parse_string_literal(first_child, diagnostics), span)
         }
+        Rule::path => Expression::ConstantValue(first_child.as_str().to_string(), span),
         Rule::function_call => parse_function(first_child, diagnostics),
+        Rule::array_expression => parse_array(first_child, diagnostics),
         _ => unreachable!(
             "Encountered impossible literal during parsing: {:?}",
             first_child.tokens()
@@ -22,12 +25,13 @@ pub(crate) fn parse_expression(token: Pair<'_>, diagnostics: &mut Diagnostics) -
 }
 
 fn parse_function(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Expression {
-    let name: Option<String> = None;
+    let mut name: Option<Identifier> = None;
     let mut arguments = ArgumentsList::default();
     let (pair_str, span) = (pair.as_str(), pair.as_span());
 
     for current in pair.into_inner() {
         match current.as_rule() {
+            Rule::path => name = Some(current.into()),
             Rule::arguments_list => parse_arguments_list(current, &mut arguments, diagnostics),
             _ => parsing_catch_all(&current, "function"),
         }
@@ -42,6 +46,20 @@ fn parse_function(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Expression {
     }
 }
 
+fn parse_array(token: Pair<'_>, diagnostics: &mut Diagnostics) -> Expression {
+    let mut elements: Vec<Expression> = vec![];
+    let span = token.as_span();
+
+    for current in token.into_inner() {
+        match current.as_rule() {
+            Rule::expression => elements.push(parse_expression(current, diagnostics)),
+            _ => parsing_catch_all(&current, "array"),
+        }
+    }
+
+    Expression::Array(elements, Span::from(span))
+}
+
 fn parse_string_literal(token: Pair<'_>, diagnostics: &mut Diagnostics) -> String {
     assert!(token.as_rule() == Rule::string_literal);
     let contents = token.clone().into_inner().next().unwrap();
@@ -121,10 +139,10 @@ fn try_parse_unicode_codepoint(
     diagnostics: &mut Diagnostics,
 ) -> (usize, Option<char>) {
     let unicode_sequence_error = |consumed| {
-        let span = crate::ast::Span {
-            start: slice_offset,
-            end: (slice_offset + slice.len()).min(slice_offset + consumed),
-        };
+        let span = crate::ast::Span::new(
+            slice_offset,
+            (slice_offset + slice.len()).min(slice_offset + consumed),
+        );
         SchemaError::new_static("Invalid unicode escape sequence.", span)
     };
 
@@ -188,3 +206,95 @@ fn parse_codepoint(slice: &str) -> (usize, Option<u16>) {
 
     (6, Some(codepoint))
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ast::*, diagnostics::Diagnostics, parse_schema};
+
+    #[test]
+    fn parse_numeric_constant_and_array_expressions_test() {
+        let schema = r#"datasource db {
+  provider     = "postgresql"
+  relationMode = prisma
+  schemas      = ["a", "b"]
+  timeout      = -1.5
+}
+
+model User {
+  id   Int    @id
+  name String @db.VarChar(200)
+
+  @@index([id, name], map: "x")
+}
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{diagnostics:?}");
+
+        let values: Vec<_> = ast.sources().next().unwrap().properties[1..]
+            .iter()
+            .map(|property| property.value.as_ref().unwrap())
+            .collect();
+
+        assert_eq!(values[0].as_constant_value().unwrap().0, "prisma");
+        assert_eq!(values[1].describe_value_type(), "array");
+        assert_eq!(values[1].to_string(), r#"["a","b"]"#);
+        assert_eq!(values[2].as_numeric_value().unwrap().0, "-1.5");
+
+        let model = ast.models().next().unwrap();
+        assert_eq!(model.fields[1].attributes[0].name(), "db.VarChar");
+        assert_eq!(
+            model.fields[1].attributes[0].arguments.arguments[0]
+                .value
+                .as_numeric_value()
+                .unwrap()
+                .0,
+            "200"
+        );
+
+        let index = &model.attributes[0];
+        let (fields, _) = index.arguments.arguments[0].value.as_array().unwrap();
+        assert_eq!(fields[1].as_constant_value().unwrap().0, "name");
+        assert!(index.span_for_argument("map").is_some());
+    }
+
+    #[test]
+    fn parse_named_function_calls_test() {
+        let schema = r#"generator client {
+  provider = env(concat("PRISMA", "_PROVIDER"), default: "js")
+}
+
+model User {
+  id Int @id @default(autoincrement())
+}
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{diagnostics:?}");
+
+        let value = ast.generators().next().unwrap().properties[0]
+            .value
+            .as_ref()
+            .unwrap();
+        assert!(value.is_env_expression());
+        assert_eq!(
+            value.to_string(),
+            r#"env(concat("PRISMA","_PROVIDER"),default:"js")"#
+        );
+
+        let Expression::Function(name, args, _) = value else {
+            panic!("expected a function, got {value:?}");
+        };
+        assert_eq!(&schema[name.span.start..name.span.end], "env");
+        assert_eq!(args.arguments[1].name.as_ref().unwrap().name, "default");
+
+        let (inner_name, inner_args, _) = args.arguments[0].value.as_function().unwrap();
+        assert_eq!(inner_name, "concat");
+        assert_eq!(inner_args.arguments.len(), 2);
+
+        let default = &ast.models().next().unwrap().fields[0].attributes[1];
+        let (name, args, _) = default.arguments.arguments[0].value.as_function().unwrap();
+        assert_eq!(name, "autoincrement");
+        assert!(args.arguments.is_empty());
+    }
+}
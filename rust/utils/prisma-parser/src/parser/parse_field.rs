@@ -20,6 +20,7 @@ pub(crate) fn parse_field(
     let mut attributes: Vec<Attribute> = Vec::new();
     let mut field_type: Option<(FieldArity, FieldType)> = None;
     let mut comment: Option<Comment> = block_comment.and_then(parse_comment_block);
+    let mut trailing_comment: Option<Comment> = None;
 
     for current in pair.into_inner() {
         match current.as_rule() {
@@ -32,14 +33,10 @@ pub(crate) fn parse_field(
                 ))
             }
             Rule::field_attribute => attributes.push(parse_attribute(current, diagnostics)),
-            Rule::trailing_comment => {
-                comment = match (comment, parse_trailing_comment(current)) {
-                    (c, None) | (None, c) => c,
-                    (Some(existing), Some(new)) => Some(Comment {
-                        text: [existing.text, new.text].join("\n"),
-                    }),
-                };
-            }
+            Rule::trailing_comment => match parse_trailing_comment(current.clone()) {
+                Some(doc_comment) => comment = merge_comments(comment, Some(doc_comment)),
+                None => trailing_comment = parse_trailing_comment_line(current),
+            },
             _ => parsing_catch_all(&current, "field"),
         }
     }
@@ -51,6 +48,7 @@ pub(crate) fn parse_field(
             arity,
             attributes,
             documentation: comment,
+            trailing_comment,
             span: Span::from(pair_span),
         }),
         _ => Err(SchemaError::new_model_validation_error(
@@ -0,0 +1,218 @@
+use super::{
+    helpers::{parsing_catch_all, Pair},
+    parse_attribute::parse_attribute,
+    parse_comments::*,
+    parse_field::parse_field,
+    Rule,
+};
+use crate::ast::*;
+use crate::diagnostics::{Diagnostics, SchemaError};
+
+pub(crate) fn parse_model(
+    pair: Pair<'_>,
+    doc_comment: Option<Pair<'_>>,
+    diagnostics: &mut Diagnostics,
+) -> Model {
+    parse_model_like(pair, "model", true, doc_comment, diagnostics)
+}
+
+/// Views share the model syntax, so they are parsed the same way.
+pub(crate) fn parse_view(
+    pair: Pair<'_>,
+    doc_comment: Option<Pair<'_>>,
+    diagnostics: &mut Diagnostics,
+) -> View {
+    let model = parse_model_like(pair, "view", true, doc_comment, diagnostics);
+
+    View {
+        name: model.name,
+        fields: model.fields,
+        attributes: model.attributes,
+        documentation: model.documentation,
+        comments: model.comments,
+        span: model.span,
+        inner_span: model.inner_span,
+    }
+}
+
+/// Composite types share the model syntax too, but without block attributes.
+pub(crate) fn parse_composite_type(
+    pair: Pair<'_>,
+    doc_comment: Option<Pair<'_>>,
+    diagnostics: &mut Diagnostics,
+) -> CompositeType {
+    let model = parse_model_like(pair, "composite type", false, doc_comment, diagnostics);
+
+    CompositeType {
+        name: model.name,
+        fields: model.fields,
+        documentation: model.documentation,
+        comments: model.comments,
+        span: model.span,
+        inner_span: model.inner_span,
+    }
+}
+
+/// Parse a model, view or composite type. Block attributes are reported as
+/// errors unless `allow_block_attributes` is set.
+fn parse_model_like(
+    pair: Pair<'_>,
+    container_type: &'static str,
+    allow_block_attributes: bool,
+    doc_comment: Option<Pair<'_>>,
+    diagnostics: &mut Diagnostics,
+) -> Model {
+    let pair_span = pair.as_span();
+    let mut name: Option<Identifier> = None;
+    let mut attributes: Vec<Attribute> = Vec::new();
+    let mut fields: Vec<Field> = Vec::new();
+    let mut comments = BlockComments::default();
+    let mut inner_span: Option<Span> = None;
+
+    for current in pair.into_inner() {
+        match current.as_rule() {
+            Rule::MODEL_KEYWORD
+            | Rule::VIEW_KEYWORD
+            | Rule::TYPE_KEYWORD
+            | Rule::BLOCK_OPEN
+            | Rule::BLOCK_CLOSE => {}
+            Rule::identifier => name = Some(current.into()),
+            Rule::model_contents => {
+                inner_span = Some(current.as_span().into());
+
+                for item in current.into_inner() {
+                    match item.as_rule() {
+                        Rule::block_attribute if allow_block_attributes => {
+                            attributes.push(parse_attribute(item, diagnostics))
+                        }
+                        Rule::block_attribute => {
+                            diagnostics.push_error(SchemaError::new_validation_error(
+                                "Composite types cannot have block attributes.",
+                                item.as_span().into(),
+                            ))
+                        }
+                        Rule::field_declaration => match parse_field(
+                            &name.as_ref().unwrap().name,
+                            container_type,
+                            item,
+                            comments.take_pending(),
+                            diagnostics,
+                        ) {
+                            Ok(field) => fields.push(field),
+                            Err(err) => diagnostics.push_error(err),
+                        },
+                        Rule::comment_block => comments.push(item),
+                        Rule::BLOCK_LEVEL_CATCH_ALL => {
+                            diagnostics.push_error(SchemaError::new_validation_error(
+                                "This line is not a valid field or attribute definition.",
+                                item.as_span().into(),
+                            ))
+                        }
+                        _ => parsing_catch_all(&item, container_type),
+                    }
+                }
+            }
+            _ => parsing_catch_all(&current, container_type),
+        }
+    }
+
+    match name {
+        Some(name) => Model {
+            name,
+            fields,
+            attributes,
+            documentation: doc_comment.and_then(parse_comment_block),
+            comments: comments.finish(),
+            span: Span::from(pair_span),
+            inner_span: inner_span.unwrap(),
+        },
+        _ => panic!("Encountered impossible {container_type} declaration during parsing"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ast::*, diagnostics::Diagnostics, parse_schema};
+
+    #[test]
+    fn parse_model_test() {
+        let schema = r#"
+/// A user of the app.
+model User {
+  id       Int
+  /// The display name.
+  name     String?
+  tags     String[]
+  location Unsupported("point")
+}
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{diagnostics:?}");
+
+        let model = ast.models().next().unwrap();
+        assert_eq!(model.name(), "User");
+        assert_eq!(model.documentation(), Some("A user of the app."));
+
+        let arities: Vec<_> = model.fields.iter().map(|field| field.arity).collect();
+        assert_eq!(
+            arities,
+            [
+                FieldArity::Required,
+                FieldArity::Optional,
+                FieldArity::List,
+                FieldArity::Required,
+            ]
+        );
+
+        assert_eq!(model.fields[1].documentation(), Some("The display name."));
+        assert_eq!(
+            model.fields[3]
+                .field_type
+                .as_unsupported()
+                .map(|(name, _)| name),
+            Some("point")
+        );
+    }
+
+    #[test]
+    fn parse_composite_type_and_view_test() {
+        let schema = r#"type Address {
+  street String
+  city   String
+}
+
+view UserStats {
+  id    Int
+  posts Int
+}
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{diagnostics:?}");
+
+        let types: Vec<_> = ast.tops.iter().map(|top| top.get_type()).collect();
+        assert_eq!(types, ["composite type", "view"]);
+        assert_eq!(ast.composite_types().next().unwrap().fields.len(), 2);
+        assert_eq!(ast.views().next().unwrap().name(), "UserStats");
+        assert_eq!(ast.models().count(), 0);
+
+        let position = schema.find("Int").unwrap();
+        assert!(matches!(
+            ast.find_at_position(position),
+            SchemaPosition::View(_, ModelPosition::Field(_, FieldPosition::Type("Int")))
+        ));
+    }
+
+    #[test]
+    fn composite_type_block_attribute_test() {
+        let schema = "type Address {\n  street String\n\n  @@map(\"addresses\")\n}\n";
+        let mut diagnostics = Diagnostics::new();
+        parse_schema(schema, &mut diagnostics);
+
+        assert_eq!(
+            diagnostics.errors()[0].message(),
+            "Error validating: Composite types cannot have block attributes."
+        );
+    }
+}
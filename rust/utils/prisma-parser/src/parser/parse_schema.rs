@@ -1,36 +1,129 @@
-use super::{parse_source_and_generator::parse_config_block, PrismaSchemaParser, Rule};
-use crate::ast::*;
-use crate::diagnostics::{Diagnostics, SchemaError};
+use super::{
+    helpers::{Pair, SchemaParseResult},
+    map_spans::MapSpans,
+    parse_comments::parse_comment_lines,
+    parse_enum::parse_enum,
+    parse_model::{parse_composite_type, parse_model, parse_view},
+    parse_source_and_generator::parse_config_block,
+    PrismaSchemaParser, Rule,
+};
+use crate::ast::{visit::VisitMut, *};
+use crate::diagnostics::{Diagnostics, ExpectedToken, SchemaError};
 use pest::Parser;
 
+/// What may start a line outside of blocks.
+const TOP_LEVEL_EXPECTED: &[ExpectedToken] = &[
+    ExpectedToken::ModelKeyword,
+    ExpectedToken::TypeKeyword,
+    ExpectedToken::ViewKeyword,
+    ExpectedToken::EnumKeyword,
+    ExpectedToken::DatasourceKeyword,
+    ExpectedToken::GeneratorKeyword,
+    ExpectedToken::Comment,
+];
+
 /// Parse a PSL string and return its AST.
 pub fn parse_schema(schema_string: &str, diagnostics: &mut Diagnostics) -> SchemaAst {
     let schema_result = PrismaSchemaParser::parse(Rule::schema, schema_string);
+    build_schema_ast(schema_string, schema_result, diagnostics)
+}
 
+/// Build the AST from the result of the pest parser.
+pub(super) fn build_schema_ast(
+    schema_string: &str,
+    schema_result: SchemaParseResult<'_>,
+    diagnostics: &mut Diagnostics,
+) -> SchemaAst {
     match schema_result {
         Ok(mut schema_wrapped) => {
             let schema = schema_wrapped.next().unwrap();
             let mut top_level_definitions: Vec<Top> = vec![];
+            let mut comments: Vec<Comment> = vec![];
+            let mut pending_block_comment = None;
             let mut pairs = schema.into_inner().peekable();
 
             while let Some(current) = pairs.next() {
                 match current.as_rule() {
+                    Rule::model_declaration => {
+                        let keyword = current
+                            .clone()
+                            .into_inner()
+                            .find(|pair| {
+                                matches!(
+                                    pair.as_rule(),
+                                    Rule::MODEL_KEYWORD | Rule::TYPE_KEYWORD | Rule::VIEW_KEYWORD
+                                )
+                            })
+                            .expect("Expected model, type or view keyword");
+                        let doc_comment = pending_block_comment.take();
+                        check_block_is_closed(&current, diagnostics);
+
+                        top_level_definitions.push(match keyword.as_rule() {
+                            Rule::TYPE_KEYWORD => Top::CompositeType(parse_composite_type(
+                                current,
+                                doc_comment,
+                                diagnostics,
+                            )),
+                            Rule::VIEW_KEYWORD => {
+                                Top::View(parse_view(current, doc_comment, diagnostics))
+                            }
+                            _ => Top::Model(parse_model(current, doc_comment, diagnostics)),
+                        });
+                    }
+                    Rule::enum_declaration => {
+                        check_block_is_closed(&current, diagnostics);
+                        top_level_definitions.push(Top::Enum(parse_enum(
+                            current,
+                            pending_block_comment.take(),
+                            diagnostics,
+                        )));
+                    }
                     Rule::config_block => {
-                        top_level_definitions.push(parse_config_block(current, diagnostics));
+                        check_block_is_closed(&current, diagnostics);
+                        top_level_definitions.push(parse_config_block(
+                            current,
+                            pending_block_comment.take(),
+                            diagnostics,
+                        ));
                     },
+                    Rule::broken_block => {
+                        diagnostics.push_error(SchemaError::new_validation_error(
+                            "This block is invalid. A block starts with a keyword and a name, followed by an opening brace and a new line, and it ends with a closing brace.",
+                            current.as_span().into(),
+                        ));
+                        top_level_definitions.push(Top::Invalid(InvalidBlock {
+                            name: current.clone().into_inner().next().map(Identifier::from),
+                            span: current.as_span().into(),
+                        }));
+                    }
                     Rule::comment_block => {
-                        // free floating
+                        match pairs.peek().map(|b| b.as_rule()) {
+                            Some(Rule::model_declaration)
+                            | Some(Rule::enum_declaration)
+                            | Some(Rule::config_block) => {
+                                // The doc comments document the next block, the others float.
+                                comments.extend(parse_comment_lines(&current, true));
+                                pending_block_comment = Some(current);
+                            }
+                            _ => comments.extend(parse_comment_lines(&current, false)),
+                        }
                     },
                     Rule::EOI => {}
-                    Rule::CATCH_ALL => diagnostics.push_error(SchemaError::new_validation_error(
-                        "This line is invalid. It does not start with any known Prisma schema keyword.",
-                        current.as_span().into(),
-                    )),
-                    // TODO: Add view when we want it to be more visible as a feature.
-                    Rule::arbitrary_block => diagnostics.push_error(SchemaError::new_validation_error(
-                        "This block is invalid. It does not start with any known Prisma schema keyword. Valid keywords include \'model\', \'enum\', \'type\', \'datasource\' and \'generator\'.",
+                    // The grammar accepts any line, so this is where most typos end up.
+                    Rule::CATCH_ALL => diagnostics.push_error(SchemaError::new_parser_error(
+                        TOP_LEVEL_EXPECTED.to_vec(),
                         current.as_span().into(),
                     )),
+                    Rule::arbitrary_block => {
+                        diagnostics.push_error(SchemaError::new_validation_error(
+                            "This block is invalid. It does not start with any known Prisma schema keyword. Valid keywords include \'model\', \'enum\', \'type\', \'view\', \'datasource\' and \'generator\'.",
+                            current.as_span().into(),
+                        ));
+                        top_level_definitions.push(Top::Invalid(InvalidBlock {
+                            name: None,
+                            span: current.as_span().into(),
+                        }));
+                    }
                     Rule::empty_lines => (),
                     _ => unreachable!(),
                 }
@@ -38,6 +131,7 @@ pub fn parse_schema(schema_string: &str, diagnostics: &mut Diagnostics) -> Schem
 
             SchemaAst {
                 tops: top_level_definitions,
+                comments,
             }
         }
         Err(err) => {
@@ -50,27 +144,379 @@ pub fn parse_schema(schema_string: &str, diagnostics: &mut Diagnostics) -> Schem
                 }
             };
 
-            let expected = match err.variant {
-                pest::error::ErrorVariant::ParsingError { positives, .. } => {
-                    get_expected_from_error(&positives)
-                }
+            let positives = match err.variant {
+                pest::error::ErrorVariant::ParsingError { positives, .. } => positives,
                 _ => panic!("Could not construct parsing error. This should never happend."),
             };
 
-            diagnostics.push_error(SchemaError::new_parser_error(expected, location.into()));
+            diagnostics.push_error(SchemaError::new_parser_error(
+                get_expected_from_error(&positives),
+                location.into(),
+            ));
+
+            SchemaAst {
+                tops: Vec::new(),
+                comments: Vec::new(),
+            }
+        }
+    }
+}
+
+/// Parse the files of a multi-file schema into one AST, with the blocks of each
+/// file in order. The spans of the nodes and diagnostics point to the index of
+/// their file in `files`, like the file ids of a [`SchemaFiles`](prisma_diagnostics::SchemaFiles)
+/// built from the same list.
+pub fn parse_schemas<N: AsRef<str>, S: AsRef<str>>(
+    files: &[(N, S)],
+    diagnostics: &mut Diagnostics,
+) -> SchemaAst {
+    let mut schema = SchemaAst {
+        tops: Vec::new(),
+        comments: Vec::new(),
+    };
+
+    for (idx, (_, source)) in files.iter().enumerate() {
+        let file_id = FileId(idx as u32);
+        let mut file_diagnostics = Diagnostics::new();
+        let mut file = parse_schema(source.as_ref(), &mut file_diagnostics);
+
+        for error in file_diagnostics.errors() {
+            diagnostics.push_error(error.clone().in_file(file_id));
+        }
+
+        for warning in file_diagnostics.warnings() {
+            diagnostics.push_warning(warning.clone().in_file(file_id));
+        }
+
+        MapSpans(|span: Span| span.in_file(file_id)).visit_schema_mut(&mut file);
+        schema.tops.extend(file.tops);
+        schema.comments.extend(file.comments);
+    }
+
+    schema
+}
+
+/// Blocks may be left unclosed, e.g. while the user is typing. They are still
+/// parsed, but the missing brace is reported on the block header.
+fn check_block_is_closed(pair: &Pair<'_>, diagnostics: &mut Diagnostics) {
+    let mut inner = pair.clone().into_inner();
+
+    if inner
+        .clone()
+        .any(|current| current.as_rule() == Rule::BLOCK_CLOSE)
+    {
+        return;
+    }
+
+    let start = pair.as_span().start();
+    let end = inner
+        .find(|current| current.as_rule() == Rule::identifier)
+        .map_or(pair.as_span().end(), |name| name.as_span().end());
+
+    diagnostics.push_error(SchemaError::new_validation_error(
+        "This block is not closed. Its closing brace must be on a line of its own.",
+        Span::new(start, end),
+    ));
+}
+
+/// What the parser expected, from the rules it tried. Internal rules, like the
+/// catch-all ones, are left out, and so are duplicates.
+fn get_expected_from_error(positives: &[Rule]) -> Vec<ExpectedToken> {
+    let mut expected = Vec::with_capacity(positives.len());
 
-            SchemaAst { tops: Vec::new() }
+    for token in positives.iter().filter_map(|rule| expected_token(*rule)) {
+        if !expected.contains(&token) {
+            expected.push(token);
         }
     }
+
+    expected
+}
+
+fn expected_token(rule: Rule) -> Option<ExpectedToken> {
+    let token = match rule {
+        Rule::model_declaration => ExpectedToken::ModelDeclaration,
+        Rule::enum_declaration => ExpectedToken::EnumDeclaration,
+        Rule::config_block => ExpectedToken::ConfigBlock,
+        Rule::field_declaration => ExpectedToken::Field,
+        Rule::field_type
+        | Rule::base_type
+        | Rule::list_type
+        | Rule::optional_type
+        | Rule::unsupported_type
+        | Rule::unsupported_optional_list_type
+        | Rule::legacy_required_type
+        | Rule::legacy_list_type => ExpectedToken::FieldType,
+        Rule::enum_value_declaration => ExpectedToken::EnumValue,
+        Rule::key_value => ExpectedToken::Property,
+        Rule::block_attribute => ExpectedToken::BlockAttribute,
+        Rule::field_attribute => ExpectedToken::FieldAttribute,
+        Rule::arguments_list => ExpectedToken::OpeningParenthesis,
+        Rule::named_argument | Rule::empty_argument => ExpectedToken::Argument,
+        Rule::trailing_comma => ExpectedToken::Comma,
+        Rule::comment_block | Rule::trailing_comment | Rule::comment | Rule::doc_comment => {
+            ExpectedToken::Comment
+        }
+        Rule::identifier | Rule::path => ExpectedToken::Name,
+        Rule::expression | Rule::function_call => ExpectedToken::Value,
+        Rule::array_expression => ExpectedToken::Array,
+        Rule::numeric_literal => ExpectedToken::Number,
+        Rule::string_literal => ExpectedToken::String,
+        Rule::BLOCK_OPEN => ExpectedToken::BlockOpen,
+        Rule::BLOCK_CLOSE => ExpectedToken::BlockClose,
+        Rule::MODEL_KEYWORD => ExpectedToken::ModelKeyword,
+        Rule::TYPE_KEYWORD => ExpectedToken::TypeKeyword,
+        Rule::VIEW_KEYWORD => ExpectedToken::ViewKeyword,
+        Rule::ENUM_KEYWORD => ExpectedToken::EnumKeyword,
+        Rule::DATASOURCE_KEYWORD => ExpectedToken::DatasourceKeyword,
+        Rule::GENERATOR_KEYWORD => ExpectedToken::GeneratorKeyword,
+        Rule::LEGACY_COLON => ExpectedToken::Colon,
+        Rule::EOI => ExpectedToken::EndOfSchema,
+        // Structural, silent and catch-all rules.
+        _ => return None,
+    };
+
+    Some(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::*,
+        diagnostics::{Diagnostics, ExpectedToken, SchemaFiles},
+        parse_schema, parse_schemas,
+    };
+
+    #[test]
+    fn unclosed_blocks_are_recovered_test() {
+        let schema = r#"model User {
+  id   Int @id
+  name String
+
+model Post {
+  id Int @id
+}
+
+enum Role {
+  USER
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+
+        let models: Vec<_> = ast.models().map(|model| model.name()).collect();
+        assert_eq!(models, ["User", "Post"]);
+        assert_eq!(ast.models().next().unwrap().fields.len(), 2);
+        assert_eq!(ast.enums().next().unwrap().values.len(), 1);
+
+        let error_spans: Vec<_> = diagnostics
+            .errors()
+            .iter()
+            .map(|error| &schema[error.span().start..error.span().end])
+            .collect();
+        assert_eq!(error_spans, ["model User", "enum Role"]);
+    }
+
+    #[test]
+    fn broken_blocks_become_invalid_blocks_test() {
+        let schema = r#"model {
+  id Int @id
+}
+
+datasource db {
+  provider = "postgres"
+}
+
+generator
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+
+        let tops: Vec<_> = ast.tops.iter().map(|top| top.get_type()).collect();
+        assert_eq!(tops, ["invalid block", "source", "invalid block"]);
+        assert_eq!(diagnostics.errors().len(), 2);
+
+        let invalid_block = ast.invalid_blocks().next().unwrap();
+        assert!(invalid_block.name.is_none());
+        assert_eq!(
+            &schema[invalid_block.span.start..invalid_block.span.end],
+            "model {\n  id Int @id\n}"
+        );
+
+        assert!(matches!(
+            ast.find_at_position(schema.find("id Int").unwrap()),
+            SchemaPosition::Invalid(_)
+        ));
+    }
+
+    #[test]
+    fn expected_rules_are_described_test() {
+        use super::{get_expected_from_error, Rule};
+
+        assert_eq!(
+            get_expected_from_error(&[Rule::key_value, Rule::BLOCK_CLOSE, Rule::CATCH_ALL]),
+            [ExpectedToken::Property, ExpectedToken::BlockClose]
+        );
+        assert_eq!(
+            get_expected_from_error(&[Rule::base_type, Rule::list_type, Rule::string_literal]),
+            [ExpectedToken::FieldType, ExpectedToken::String]
+        );
+        assert!(get_expected_from_error(&[Rule::BLOCK_LEVEL_CATCH_ALL]).is_empty());
+
+        let schema = "modle User {}\n";
+        let mut diagnostics = Diagnostics::new();
+        parse_schema(schema, &mut diagnostics);
+
+        let error = &diagnostics.errors()[0];
+        assert_eq!(
+            error.message(),
+            "Unexpected token. Expected `model`, `type`, `view`, `enum`, `datasource`, `generator` or a comment."
+        );
+        assert_eq!(error.expected()[0], ExpectedToken::ModelKeyword);
+        assert_eq!(&schema[error.span().start..error.span().end], schema);
+    }
+
+    #[test]
+    fn free_floating_and_trailing_comments_test() {
+        let schema = r#"// Floating.
+/// Floating doc.
+
+// Regular, above a model.
+/// The user.
+model User {
+  id Int @id
 }
 
-fn get_expected_from_error(positives: &[Rule]) -> String {
-    use std::fmt::Write as _;
-    let mut out = String::with_capacity(positives.len() * 6);
+datasource db {
+  provider = "postgres" // The provider.
+  url      = env("DATABASE_URL")
+}
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{diagnostics:?}");
+
+        let comments: Vec<_> = ast
+            .comments
+            .iter()
+            .map(|comment| (comment.text.as_str(), comment.is_doc))
+            .collect();
+        assert_eq!(
+            comments,
+            [
+                ("Floating.", false),
+                ("Floating doc.", true),
+                ("Regular, above a model.", false),
+            ]
+        );
+
+        let span = ast.comments[1].span;
+        assert_eq!(&schema[span.start..span.end], "/// Floating doc.");
+        assert_eq!(
+            ast.models().next().unwrap().documentation(),
+            Some("The user.")
+        );
 
-    for positive in positives {
-        write!(out, "{positive:?}").unwrap();
+        let properties = &ast.sources().next().unwrap().properties;
+        let trailing = properties[0].trailing_comment.as_ref().unwrap();
+        assert_eq!(trailing.text, "The provider.");
+        assert!(!trailing.is_doc);
+        assert_eq!(
+            &schema[trailing.span.start..trailing.span.end],
+            "// The provider."
+        );
+        assert!(properties[1].trailing_comment.is_none());
     }
 
-    out
+    #[test]
+    fn comments_inside_blocks_test() {
+        let schema = r#"model User {
+  // Regular, above a field.
+  /// The id.
+  id Int @id // The key.
+  name String /// Also documentation.
+
+  // Before the attributes.
+  @@map("users")
+  /// Documents nothing.
+}
+
+enum Role {
+  USER // Trailing.
+  // Floating.
+}
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{diagnostics:?}");
+
+        let model = ast.models().next().unwrap();
+        let comments: Vec<_> = model
+            .comments
+            .iter()
+            .map(|comment| (comment.text.as_str(), comment.is_doc))
+            .collect();
+        assert_eq!(
+            comments,
+            [
+                ("Regular, above a field.", false),
+                ("Before the attributes.", false),
+                ("Documents nothing.", true),
+            ]
+        );
+
+        let id = &model.fields[0];
+        assert_eq!(id.documentation(), Some("The id."));
+        assert_eq!(id.trailing_comment.as_ref().unwrap().text, "The key.");
+        assert_eq!(model.fields[1].documentation(), Some("Also documentation."));
+        assert!(model.fields[1].trailing_comment.is_none());
+
+        let r#enum = ast.enums().next().unwrap();
+        let trailing = r#enum.values[0].trailing_comment.as_ref().unwrap();
+        assert_eq!(
+            &schema[trailing.span.start..trailing.span.end],
+            "// Trailing."
+        );
+        assert_eq!(r#enum.comments[0].text, "Floating.");
+    }
+
+    #[test]
+    fn multiple_files_are_merged_test() {
+        let files = [
+            (
+                "db.prisma",
+                "datasource db {\n  provider = \"postgres\"\n}\n",
+            ),
+            (
+                "user.prisma",
+                "// Users.\nmodel User {\n  id Int @id\n}\n\nmodel {\n}\n",
+            ),
+        ];
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schemas(&files, &mut diagnostics);
+
+        let file_ids: Vec<_> = ast.tops.iter().map(|top| top.span().file_id).collect();
+        assert_eq!(file_ids, [FileId(0), FileId(1), FileId(1)]);
+
+        let user = ast.models().next().unwrap();
+        assert_eq!(
+            user.fields[0].name.span,
+            Span::new(25, 27).in_file(FileId(1))
+        );
+        assert_eq!(ast.comments[0].span.file_id, FileId(1));
+        assert!(matches!(
+            ast.find_top_at_position_in_file(FileId(1), 25),
+            Some(TopId::Model(_))
+        ));
+        assert!(matches!(
+            ast.find_top_at_position_in_file(FileId(0), 25),
+            Some(TopId::Source(_))
+        ));
+        assert_eq!(ast.find_top_at_position_in_file(FileId(2), 25), None);
+
+        assert_eq!(diagnostics.errors().len(), 1);
+        assert_eq!(diagnostics.errors()[0].span().file_id, FileId(1));
+
+        let pretty = diagnostics.to_pretty_string_in_files(&SchemaFiles::new(files));
+        assert!(pretty.contains("user.prisma:6"), "{pretty}");
+    }
 }
@@ -8,11 +8,16 @@ use crate::ast::*;
 use crate::diagnostics::{Diagnostics, SchemaError};
 
 #[track_caller]
-pub(crate) fn parse_config_block(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Top {
+pub(crate) fn parse_config_block(
+    pair: Pair<'_>,
+    doc_comment: Option<Pair<'_>>,
+    diagnostics: &mut Diagnostics,
+) -> Top {
     let pair_span = pair.as_span();
     let mut name: Option<Identifier> = None;
     let mut properties = Vec::new();
-    let mut comment: Option<Comment> = None;
+    let comment: Option<Comment> = doc_comment.and_then(parse_comment_block);
+    let mut comments = BlockComments::default();
     let mut kw = None;
     let mut inner_span: Option<Span> = None;
 
@@ -22,8 +27,12 @@ pub(crate) fn parse_config_block(pair: Pair<'_>, diagnostics: &mut Diagnostics)
                 inner_span = Some(current.as_span().into());
                 for item in current.into_inner() {
                     match item.as_rule() {
-                        Rule::key_value => properties.push(parse_key_value(item, diagnostics)),
-                        Rule::comment_block => comment = parse_comment_block(item),
+                        Rule::key_value => properties.push(parse_key_value(
+                            item,
+                            comments.take_pending(),
+                            diagnostics,
+                        )),
+                        Rule::comment_block => comments.push(item),
                         Rule::BLOCK_LEVEL_CATCH_ALL => {
                             let msg = format!(
                                 "This line is not a valid definition within a {}.",
@@ -39,18 +48,29 @@ pub(crate) fn parse_config_block(pair: Pair<'_>, diagnostics: &mut Diagnostics)
                 }
             }
             Rule::identifier => name = Some(current.into()),
-            Rule::DATASOURCE_KEYWORD => kw = Some(current.as_str()),
+            Rule::DATASOURCE_KEYWORD | Rule::GENERATOR_KEYWORD => kw = Some(current.as_str()),
             Rule::BLOCK_OPEN | Rule::BLOCK_CLOSE => {}
 
             _ => parsing_catch_all(&current, "source"),
         }
     }
 
+    let comments = comments.finish();
+
     match kw {
         Some("datasource") => Top::Source(SourceConfig {
             name: name.unwrap(),
             properties,
             documentation: comment,
+            comments,
+            span: Span::from(pair_span),
+            inner_span: inner_span.unwrap(),
+        }),
+        Some("generator") => Top::Generator(GeneratorConfig {
+            name: name.unwrap(),
+            properties,
+            documentation: comment,
+            comments,
             span: Span::from(pair_span),
             inner_span: inner_span.unwrap(),
         }),
@@ -58,16 +78,21 @@ pub(crate) fn parse_config_block(pair: Pair<'_>, diagnostics: &mut Diagnostics)
     }
 }
 
-fn parse_key_value(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> ConfigBlockProperty {
+fn parse_key_value(
+    pair: Pair<'_>,
+    doc_comment: Option<Pair<'_>>,
+    diagnostics: &mut Diagnostics,
+) -> ConfigBlockProperty {
     let mut name: Option<Identifier> = None;
     let mut value: Option<Expression> = None;
+    let mut trailing_comment: Option<Comment> = None;
     let (pair_span, pair_str) = (pair.as_span(), pair.as_str());
 
     for current in pair.into_inner() {
         match current.as_rule() {
             Rule::identifier => name = Some(current.into()),
             Rule::expression => value = Some(parse_expression(current, diagnostics)),
-            Rule::trailing_comment => (),
+            Rule::trailing_comment => trailing_comment = parse_trailing_comment_line(current),
             _ => unreachable!(
                 "Encountered impossible source property declaration during parsing: {:?}",
                 current.tokens()
@@ -79,6 +104,8 @@ fn parse_key_value(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> ConfigBlock
         (Some(name), value) => ConfigBlockProperty {
             name,
             value,
+            trailing_comment,
+            documentation: doc_comment.and_then(parse_comment_block),
             span: Span::from(pair_span),
         },
         _ => unreachable!(
@@ -87,3 +114,175 @@ fn parse_key_value(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> ConfigBlock
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ast::*, diagnostics::Diagnostics, parse_schema};
+
+    #[test]
+    fn parse_generator_test() {
+        let schema = r#"generator client {
+  provider = "prisma-client-js"
+  output   = "./generated"
+}
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{diagnostics:?}");
+
+        let generator = ast.generators().next().unwrap();
+        assert_eq!(generator.name(), "client");
+
+        let properties: Vec<_> = generator
+            .properties
+            .iter()
+            .map(|property| property.name.name.as_str())
+            .collect();
+        assert_eq!(properties, ["provider", "output"]);
+
+        let position = schema.find("./generated").unwrap();
+        assert!(matches!(
+            ast.find_at_position(position),
+            SchemaPosition::Generator(_, GeneratorPosition::Property("output", _))
+        ));
+    }
+
+    #[test]
+    fn config_block_documentation_test() {
+        let schema = r#"/// The main database.
+datasource db {
+  /// Which database.
+  provider = "postgres"
+
+  // Not documentation.
+  /// Where it is.
+  url      = env("DATABASE_URL") // trailing
+}
+"#;
+        let mut diagnostics = Diagnostics::new();
+        let ast = parse_schema(schema, &mut diagnostics);
+        assert!(!diagnostics.has_errors(), "{diagnostics:?}");
+
+        let source = ast.sources().next().unwrap();
+        assert_eq!(source.documentation(), Some("The main database."));
+
+        let docs: Vec<_> = source
+            .properties
+            .iter()
+            .map(|property| property.documentation())
+            .collect();
+        assert_eq!(docs, [Some("Which database."), Some("Where it is.")]);
+    }
+
+    #[test]
+    fn config_block_position_test() {
+        let schema = r#"datasource db {
+  provider = "postgres"
+  url      = env("DATABASE_URL", )
+  relationMode = prisma(a, b)
+  shadowDatabaseUrl = env(name: "SHADOW")
+}
+"#;
+        let ast = parse_schema(schema, &mut Diagnostics::new());
+        let at = |needle: &str, offset: usize| {
+            ast.find_at_position(schema.find(needle).unwrap() + offset)
+        };
+
+        assert!(matches!(
+            at("datasource", 2),
+            SchemaPosition::DataSource(_, SourcePosition::Keyword)
+        ));
+        assert!(matches!(
+            at("db", 1),
+            SchemaPosition::DataSource(_, SourcePosition::Name("db"))
+        ));
+        assert!(matches!(
+            at("provider", 1),
+            SchemaPosition::DataSource(
+                _,
+                SourcePosition::Property("provider", PropertyPosition::Property)
+            )
+        ));
+        assert!(matches!(
+            at("postgres", 1),
+            SchemaPosition::DataSource(
+                _,
+                SourcePosition::Property(
+                    "provider",
+                    PropertyPosition::Value(ExpressionPosition::StringValue("postgres"))
+                )
+            )
+        ));
+
+        let SchemaPosition::DataSource(
+            _,
+            SourcePosition::Property("url", PropertyPosition::Value(position)),
+        ) = at("env", 1)
+        else {
+            panic!()
+        };
+        assert!(matches!(position, ExpressionPosition::Function("env")));
+
+        let SchemaPosition::DataSource(
+            _,
+            SourcePosition::Property("url", PropertyPosition::Value(position)),
+        ) = at("DATABASE_URL", 0)
+        else {
+            panic!()
+        };
+        let ExpressionPosition::FunctionArgument("env", 0, None, inner) = position else {
+            panic!("{position:?}")
+        };
+        assert!(matches!(
+            *inner,
+            ExpressionPosition::StringValue("DATABASE_URL")
+        ));
+
+        let SchemaPosition::DataSource(
+            _,
+            SourcePosition::Property("url", PropertyPosition::Value(position)),
+        ) = at(", )", 2)
+        else {
+            panic!()
+        };
+        assert!(matches!(
+            position,
+            ExpressionPosition::AfterTrailingComma("env")
+        ));
+
+        // Between two arguments, the cursor is on the next one.
+        let SchemaPosition::DataSource(
+            _,
+            SourcePosition::Property(_, PropertyPosition::Value(position)),
+        ) = at(", b", 1)
+        else {
+            panic!()
+        };
+        assert!(matches!(
+            position,
+            ExpressionPosition::FunctionArgument("prisma", 1, _, _)
+        ));
+
+        // The closing parenthesis ends the function call.
+        let SchemaPosition::DataSource(
+            _,
+            SourcePosition::Property("url", PropertyPosition::Value(position)),
+        ) = at(", )", 3)
+        else {
+            panic!()
+        };
+        assert!(matches!(position, ExpressionPosition::Expression));
+
+        let SchemaPosition::DataSource(
+            _,
+            SourcePosition::Property(_, PropertyPosition::Value(position)),
+        ) = at("SHADOW", 0)
+        else {
+            panic!()
+        };
+        let ExpressionPosition::FunctionArgument("env", 0, Some("name"), inner) = position else {
+            panic!("{position:?}")
+        };
+        assert!(matches!(*inner, ExpressionPosition::StringValue("SHADOW")));
+    }
+}
@@ -0,0 +1,417 @@
+use super::{map_spans::MapSpans, parse_schema};
+use crate::ast::{visit::VisitMut, *};
+use crate::diagnostics::Diagnostics;
+
+/// A replacement of the text at `span` with `new_text`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    /// The replaced text, in the schema before the edit.
+    pub span: Span,
+    /// The text that replaces it.
+    pub new_text: String,
+}
+
+impl TextEdit {
+    /// Apply non-overlapping edits to a string. The edit spans refer to `text`.
+    pub fn apply(text: &str, edits: &[TextEdit]) -> String {
+        let mut edits: Vec<&TextEdit> = edits.iter().collect();
+        edits.sort_by_key(|edit| edit.span.start);
+
+        let mut out = String::with_capacity(text.len());
+        let mut cursor = 0;
+
+        for edit in edits {
+            out.push_str(&text[cursor..edit.span.start]);
+            out.push_str(&edit.new_text);
+            cursor = edit.span.end;
+        }
+
+        out.push_str(&text[cursor..]);
+        out
+    }
+
+    /// How much the edit moves the text after it.
+    fn delta(&self) -> isize {
+        self.new_text.len() as isize - (self.span.end - self.span.start) as isize
+    }
+}
+
+/// Update the AST of a schema after some text edits, without parsing the whole
+/// schema again. `edits` are non-overlapping and refer to the text `previous` was
+/// parsed from, `previous_diagnostics` are the errors of that parse, and
+/// `schema_string` is the text after the edits.
+///
+/// In a multi-file schema from [`parse_schemas()`](super::parse_schemas), the
+/// edits are all in the file of their spans, and `schema_string` is the new text
+/// of that file. The other files are left as they are.
+///
+/// Only the top-level blocks the edits touch are parsed again, with the comments
+/// above them. The spans of everything after them are shifted. The errors of the
+/// blocks that were not parsed again are carried over from `previous_diagnostics`,
+/// so that `diagnostics` gets the same errors as a full parse.
+pub fn reparse_schema(
+    previous: SchemaAst,
+    previous_diagnostics: &Diagnostics,
+    edits: &[TextEdit],
+    schema_string: &str,
+    diagnostics: &mut Diagnostics,
+) -> SchemaAst {
+    let (Some(start), Some(end)) = (
+        edits.iter().map(|edit| edit.span.start).min(),
+        edits.iter().map(|edit| edit.span.end).max(),
+    ) else {
+        for error in previous_diagnostics.errors() {
+            diagnostics.push_error(error.clone());
+        }
+        for warning in previous_diagnostics.warnings() {
+            diagnostics.push_warning(warning.clone());
+        }
+        return previous;
+    };
+
+    let file_id = edits[0].span.file_id;
+    let delta: isize = edits.iter().map(TextEdit::delta).sum();
+    let previous_len = schema_string.len().saturating_add_signed(-delta);
+    let SchemaAst {
+        mut tops,
+        mut comments,
+    } = previous;
+
+    // The blocks of the file, they are in a row.
+    let file_tops = tops.partition_point(|top| top.span().file_id < file_id)
+        ..tops.partition_point(|top| top.span().file_id <= file_id);
+    let in_file = &tops[file_tops.clone()];
+
+    // The edited blocks, including the comments and empty lines above them: the
+    // first block ending at or after the edits start, up to the block the edits
+    // end in. Past the last block, the region extends to the end of the file.
+    let mut first = in_file.partition_point(|top| top.span().end < start);
+    let mut last = in_file.partition_point(|top| top.span().end <= end);
+
+    // An unclosed block takes the lines after it, up to the next block: the
+    // edited lines too. The text before the edits did not move.
+    if first > 0 && !is_closed(&in_file[first - 1], schema_string) {
+        first -= 1;
+    }
+
+    let region_start = first
+        .checked_sub(1)
+        .map_or(0, |idx| in_file[idx].span().end);
+    let (region_end, region_diagnostics, mut region) = loop {
+        // The text after the last block did not change, only moved.
+        let region_end = in_file.get(last).map_or(previous_len, |top| {
+            line_break_end(schema_string, top.span().end.saturating_add_signed(delta))
+                .saturating_add_signed(-delta)
+        });
+        let region_text = &schema_string[region_start..region_end.saturating_add_signed(delta)];
+        let mut region_diagnostics = Diagnostics::new();
+        let region = parse_schema(region_text, &mut region_diagnostics);
+
+        // The last block of the region must still be closed, or it takes the
+        // lines after the region: parse up to the end of the file then.
+        if region_end < previous_len
+            && region
+                .tops
+                .last()
+                .is_some_and(|top| !is_closed(top, region_text))
+        {
+            last = in_file.len();
+            continue;
+        }
+
+        break (region_end, region_diagnostics, region);
+    };
+
+    let is_before = |span: Span| {
+        span.file_id < file_id || (span.file_id == file_id && span.start < region_start)
+    };
+    let is_after = |span: Span| {
+        span.file_id > file_id
+            || (span.file_id == file_id
+                && (span.start > region_end || (span.start == region_end && span.end > region_end)))
+    };
+
+    // Only the diagnostics after the region in the same file move.
+    let after_delta = |span: Span| if span.file_id == file_id { delta } else { 0 };
+
+    for error in previous_diagnostics.errors() {
+        if is_before(error.span()) {
+            diagnostics.push_error(error.clone());
+        }
+    }
+    for error in region_diagnostics.errors() {
+        let error = error.clone().shifted(region_start as isize);
+        diagnostics.push_error(error.in_file(file_id));
+    }
+    for error in previous_diagnostics.errors() {
+        if is_after(error.span()) {
+            diagnostics.push_error(error.clone().shifted(after_delta(error.span())));
+        }
+    }
+
+    for warning in previous_diagnostics.warnings() {
+        if is_before(warning.span()) {
+            diagnostics.push_warning(warning.clone());
+        }
+    }
+    for warning in region_diagnostics.warnings() {
+        let warning = warning.clone().shifted(region_start as isize);
+        diagnostics.push_warning(warning.in_file(file_id));
+    }
+    for warning in previous_diagnostics.warnings() {
+        if is_after(warning.span()) {
+            diagnostics.push_warning(warning.clone().shifted(after_delta(warning.span())));
+        }
+    }
+
+    MapSpans(|span: Span| span.shifted(region_start as isize).in_file(file_id))
+        .visit_schema_mut(&mut region);
+
+    let replaced = file_tops.start + first..file_tops.start + (last + 1).min(in_file.len());
+    let mut shift = MapSpans(|span: Span| span.shifted(delta));
+    for top in &mut tops[replaced.end..file_tops.end] {
+        shift.visit_top_mut(top);
+    }
+
+    tops.splice(replaced, region.tops);
+
+    comments.retain(|comment| {
+        let span = comment.span;
+        span.file_id != file_id || span.start < region_start || span.end > region_end
+    });
+    for comment in &mut comments {
+        if comment.span.file_id == file_id && comment.span.start >= region_end {
+            shift.visit_comment_mut(comment);
+        }
+    }
+    comments.extend(region.comments);
+    comments.sort_by_key(|comment| (comment.span.file_id, comment.span.start));
+
+    SchemaAst { tops, comments }
+}
+
+/// The offset after the line break that ends the line at `offset`, if there is
+/// only whitespace before it. Lines that fail to parse keep their line break.
+fn line_break_end(text: &str, offset: usize) -> usize {
+    let rest = &text[offset..];
+    let line = rest.trim_start_matches([' ', '\t']);
+
+    let line_break = if line.starts_with("\r\n") {
+        2
+    } else if line.starts_with(['\n', '\r']) {
+        1
+    } else {
+        return offset;
+    };
+
+    offset + rest.len() - line.len() + line_break
+}
+
+/// Is the block closed by a brace? The contents of unclosed blocks go on to the
+/// end of the block, and invalid ones on to the next block. `text` is the text
+/// the spans of the block point into.
+fn is_closed(top: &Top, text: &str) -> bool {
+    let inner_span = match top {
+        Top::Model(model) => model.inner_span,
+        Top::CompositeType(composite_type) => composite_type.inner_span,
+        Top::View(view) => view.inner_span,
+        Top::Enum(r#enum) => r#enum.inner_span,
+        Top::Generator(generator) => generator.inner_span,
+        Top::Source(source) => source.inner_span,
+        // A closed invalid block ends with a brace alone on its last line. Taking
+        // the others as unclosed only parses more.
+        Top::Invalid(invalid_block) => {
+            let block = &text[invalid_block.span.start..invalid_block.span.end];
+            let last_line = block.rsplit(['\n', '\r']).next().unwrap_or(block);
+
+            return last_line.trim_start() == "}";
+        }
+    };
+
+    top.span().end > inner_span.end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_schemas;
+
+    const SCHEMA: &str = r#"datasource db {
+  provider = "postgres"
+  url      = env("DATABASE_URL")
+}
+
+// Free floating.
+
+/// A user.
+model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id       Int    @id
+  title    String @db.VarChar(200)
+}
+
+enum Role {
+  USER
+  ADMIN @map("admin")
+}
+"#;
+
+    /// Reparse after the edits, and compare the AST and the errors with a full
+    /// parse of the new text.
+    fn assert_reparse_schema(schema: &str, edits: &[TextEdit]) -> (SchemaAst, Diagnostics) {
+        let mut previous_diagnostics = Diagnostics::new();
+        let previous = parse_schema(schema, &mut previous_diagnostics);
+        let new_schema = TextEdit::apply(schema, edits);
+
+        let mut diagnostics = Diagnostics::new();
+        let reparsed = reparse_schema(
+            previous,
+            &previous_diagnostics,
+            edits,
+            &new_schema,
+            &mut diagnostics,
+        );
+
+        let mut expected_diagnostics = Diagnostics::new();
+        let expected = parse_schema(&new_schema, &mut expected_diagnostics);
+
+        assert_eq!(format!("{reparsed:?}"), format!("{expected:?}"));
+        assert_eq!(
+            format!("{:?}", diagnostics.errors()),
+            format!("{:?}", expected_diagnostics.errors())
+        );
+        assert_eq!(diagnostics.warnings(), expected_diagnostics.warnings());
+        (reparsed, diagnostics)
+    }
+
+    fn assert_reparse(edits: &[TextEdit]) -> SchemaAst {
+        assert_reparse_schema(SCHEMA, edits).0
+    }
+
+    fn edit(start: usize, end: usize, new_text: &str) -> TextEdit {
+        TextEdit {
+            span: Span::new(start, end),
+            new_text: new_text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn reparse_edit_in_block_test() {
+        let start = SCHEMA.find("posts Post[]").unwrap();
+        let ast = assert_reparse(&[edit(start, start + 5, "articles")]);
+
+        assert_eq!(ast.models().next().unwrap().fields[1].name.name, "articles");
+    }
+
+    #[test]
+    fn reparse_edit_between_blocks_test() {
+        let start = SCHEMA.find("/// A user.").unwrap();
+        assert_reparse(&[edit(start + 4, start + 10, "An account.")]);
+
+        let start = SCHEMA.find("// Free floating.").unwrap();
+        assert_reparse(&[edit(start, start + 17, "model Tag {\n  id Int @id\n}")]);
+        assert_reparse(&[edit(SCHEMA.len(), SCHEMA.len(), "\nenum Other {\n  A\n}\n")]);
+    }
+
+    #[test]
+    fn reparse_several_edits_test() {
+        let provider = SCHEMA.find("postgres").unwrap();
+        let admin = SCHEMA.find("\"admin\"").unwrap();
+
+        assert_reparse(&[
+            edit(admin, admin + 7, "\"administrator\""),
+            edit(provider, provider + 8, "mysql"),
+        ]);
+    }
+
+    #[test]
+    fn reparse_unclosed_block_test() {
+        let start = SCHEMA.find("}\n\n// Free").unwrap();
+        let (ast, diagnostics) = assert_reparse_schema(SCHEMA, &[edit(start, start + 1, "")]);
+
+        assert_eq!(ast.tops.len(), 4);
+        assert!(diagnostics.errors()[0].message().contains("not closed"));
+
+        // A brace in a comment does not close the block.
+        let start = SCHEMA.find("}\n\nenum").unwrap();
+        assert_reparse_schema(SCHEMA, &[edit(start, start + 1, "// }")]);
+    }
+
+    #[test]
+    fn reparse_keeps_errors_outside_of_edits_test() {
+        let schema = "model A {\n  id Int\n\nmodel B {\n  id Int\n}\n\nmodel C {\n  nope\n}\n";
+
+        // The errors of `A` and `C` are not parsed again, but they are still there.
+        let start = schema.find("id Int\n}").unwrap();
+        let (_, diagnostics) = assert_reparse_schema(schema, &[edit(start, start + 2, "key")]);
+        assert_eq!(diagnostics.errors().len(), 2);
+
+        // The unclosed `A` takes the lines added above `B`.
+        let start = schema.find("model B").unwrap();
+        assert_reparse_schema(schema, &[edit(start, start, "  name String\n")]);
+    }
+
+    #[test]
+    fn reparse_unclosed_invalid_block_test() {
+        // The invalid `generator` block goes on to the next block keyword.
+        let schema = "generator\n  foo\n\nmodel {\n}\n";
+        let start = schema.find("model {").unwrap();
+
+        assert_reparse_schema(schema, &[edit(start + 3, start + 7, "😀{")]);
+    }
+
+    #[test]
+    fn reparse_error_on_last_line_test() {
+        // The invalid line `}` at the end of the region keeps its line break.
+        let schema = "model A {\n  id Int\n\nmodel B {\n  id Int\n}\n\nmodel C {\n  nope\n}\n";
+
+        assert_reparse_schema(schema, &[edit(40, 43, "enum E {\n A\n}\n")]);
+    }
+
+    #[test]
+    fn reparse_file_of_multiple_files_test() {
+        let files = [
+            (
+                "a.prisma",
+                "model A {\n  id Int\n\nmodel B {\n  id Int\n}\n",
+            ),
+            ("b.prisma", SCHEMA),
+            ("c.prisma", "// C.\nmodel C {\n  nope\n}\n"),
+        ];
+        let mut previous_diagnostics = Diagnostics::new();
+        let previous = parse_schemas(&files, &mut previous_diagnostics);
+
+        let start = SCHEMA.find("posts Post[]").unwrap();
+        let edits = [TextEdit {
+            span: Span::new(start, start + 5).in_file(FileId(1)),
+            new_text: "articles".to_owned(),
+        }];
+        let new_files = [
+            files[0],
+            ("b.prisma", &TextEdit::apply(SCHEMA, &edits)),
+            files[2],
+        ];
+
+        let mut diagnostics = Diagnostics::new();
+        let reparsed = reparse_schema(
+            previous,
+            &previous_diagnostics,
+            &edits,
+            new_files[1].1,
+            &mut diagnostics,
+        );
+
+        let mut expected_diagnostics = Diagnostics::new();
+        let expected = parse_schemas(&new_files, &mut expected_diagnostics);
+
+        assert_eq!(format!("{reparsed:?}"), format!("{expected:?}"));
+        assert_eq!(
+            format!("{:?}", diagnostics.errors()),
+            format!("{:?}", expected_diagnostics.errors())
+        );
+    }
+}
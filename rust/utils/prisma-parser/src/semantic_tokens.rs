@@ -0,0 +1,233 @@
+use crate::{
+    ast::Span,
+    cst::{NodeKind, SyntaxElement, SyntaxNode, TokenKind},
+    diagnostics::{Encoding, LineIndex},
+};
+
+/// What a [`SemanticToken`] is, for highlighting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SemanticTokenType {
+    /// `model`, `enum`, `datasource`, ...
+    Keyword,
+    /// The name of a block, like `db` in `datasource db`.
+    BlockName,
+    /// The name of a datasource or generator property.
+    PropertyName,
+    /// A string literal, quotes and escapes included.
+    String,
+    /// The name of a function, like `env`.
+    Function,
+    /// `// ...`
+    Comment,
+    /// `/// ...`
+    DocComment,
+    /// `{`, `=`, `(`, `@`, ...
+    Punctuation,
+}
+
+impl SemanticTokenType {
+    /// Every type, in the order of their index in [`encode_semantic_tokens()`].
+    /// Editors register it as the legend.
+    pub const ALL: [SemanticTokenType; 8] = [
+        SemanticTokenType::Keyword,
+        SemanticTokenType::BlockName,
+        SemanticTokenType::PropertyName,
+        SemanticTokenType::String,
+        SemanticTokenType::Function,
+        SemanticTokenType::Comment,
+        SemanticTokenType::DocComment,
+        SemanticTokenType::Punctuation,
+    ];
+
+    /// The name of the type in the legend.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SemanticTokenType::Keyword => "keyword",
+            SemanticTokenType::BlockName => "blockName",
+            SemanticTokenType::PropertyName => "propertyName",
+            SemanticTokenType::String => "string",
+            SemanticTokenType::Function => "function",
+            SemanticTokenType::Comment => "comment",
+            SemanticTokenType::DocComment => "docComment",
+            SemanticTokenType::Punctuation => "punctuation",
+        }
+    }
+
+    fn index(self) -> u32 {
+        SemanticTokenType::ALL
+            .iter()
+            .position(|token_type| *token_type == self)
+            .unwrap() as u32
+    }
+}
+
+/// A classified token of a schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SemanticToken {
+    pub token_type: SemanticTokenType,
+    pub span: Span,
+}
+
+/// Classify the tokens of a concrete syntax tree for highlighting, in source
+/// order. Whitespace, numbers and other names are left out, and so is the text
+/// of invalid lines and blocks, except for their comments.
+///
+/// ```
+/// use prisma_parser::{parse_cst, semantic_tokens, SemanticTokenType};
+///
+/// let schema = r#"datasource db {
+///   url = "a \"quoted\" word"
+/// }"#;
+/// let tokens = semantic_tokens(&parse_cst(schema));
+/// let url = tokens
+///     .iter()
+///     .find(|token| token.token_type == SemanticTokenType::String)
+///     .unwrap();
+///
+/// assert_eq!(&schema[url.span.start..url.span.end], r#""a \"quoted\" word""#);
+/// ```
+pub fn semantic_tokens(cst: &SyntaxNode) -> Vec<SemanticToken> {
+    let mut tokens = Vec::new();
+    collect_tokens(cst, &mut tokens);
+    tokens
+}
+
+fn collect_tokens(node: &SyntaxNode, out: &mut Vec<SemanticToken>) {
+    let is_invalid = matches!(node.kind, NodeKind::InvalidBlock | NodeKind::InvalidLine);
+
+    // Only the first name in these nodes is special, e.g. not the values of properties.
+    let name_type = match node.kind {
+        NodeKind::Model
+        | NodeKind::CompositeType
+        | NodeKind::View
+        | NodeKind::Enum
+        | NodeKind::Datasource
+        | NodeKind::Generator => Some(SemanticTokenType::BlockName),
+        NodeKind::Property => Some(SemanticTokenType::PropertyName),
+        NodeKind::Function => Some(SemanticTokenType::Function),
+        _ => None,
+    };
+    let mut seen_name = false;
+
+    for child in &node.children {
+        let token = match child {
+            SyntaxElement::Node(child) => {
+                collect_tokens(child, out);
+                continue;
+            }
+            SyntaxElement::Token(token) => token,
+        };
+
+        let token_type = match token.kind {
+            TokenKind::Comment => Some(SemanticTokenType::Comment),
+            TokenKind::DocComment => Some(SemanticTokenType::DocComment),
+            _ if is_invalid => None,
+            TokenKind::Keyword => Some(SemanticTokenType::Keyword),
+            TokenKind::String => Some(SemanticTokenType::String),
+            TokenKind::Punctuation => Some(SemanticTokenType::Punctuation),
+            TokenKind::Identifier if !seen_name => {
+                seen_name = true;
+                name_type
+            }
+            _ => None,
+        };
+
+        if let Some(token_type) = token_type {
+            out.push(SemanticToken {
+                token_type,
+                span: token.span,
+            });
+        }
+    }
+}
+
+/// Encode the tokens for the LSP `textDocument/semanticTokens` response: five
+/// numbers per token, with the line and start relative to the previous token.
+/// The columns and lengths are in the given encoding, usually UTF-16. `schema`
+/// is the text the tokens come from.
+pub fn encode_semantic_tokens(
+    tokens: &[SemanticToken],
+    schema: &str,
+    encoding: Encoding,
+) -> Vec<u32> {
+    let index = LineIndex::new(schema);
+    let mut data = Vec::with_capacity(tokens.len() * 5);
+    let (mut previous_line, mut previous_col) = (0, 0);
+
+    for token in tokens {
+        let start = index.line_col(token.span.start, encoding);
+        let end = index.line_col(token.span.end, encoding);
+
+        let delta_line = start.line - previous_line;
+        let delta_col = if delta_line == 0 {
+            start.col - previous_col
+        } else {
+            start.col
+        };
+
+        // Tokens do not span lines: comments stop before the line break.
+        data.extend([
+            delta_line,
+            delta_col,
+            end.col - start.col,
+            token.token_type.index(),
+            0,
+        ]);
+
+        (previous_line, previous_col) = (start.line, start.col);
+    }
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_cst;
+
+    #[test]
+    fn semantic_tokens_test() {
+        let schema = r#"/// The database.
+datasource db {
+  url = env("é\"x") // trailing
+}
+"#;
+        let tokens = semantic_tokens(&parse_cst(schema));
+        let classified: Vec<_> = tokens
+            .iter()
+            .map(|token| (&schema[token.span.start..token.span.end], token.token_type))
+            .collect();
+
+        use SemanticTokenType::*;
+        assert_eq!(
+            classified,
+            [
+                ("/// The database.", DocComment),
+                ("datasource", Keyword),
+                ("db", BlockName),
+                ("{", Punctuation),
+                ("url", PropertyName),
+                ("=", Punctuation),
+                ("env", Function),
+                ("(", Punctuation),
+                (r#""é\"x""#, String),
+                (")", Punctuation),
+                ("// trailing", Comment),
+                ("}", Punctuation),
+            ]
+        );
+
+        let data = encode_semantic_tokens(&tokens, schema, Encoding::Utf16);
+        let encoded: Vec<_> = data.chunks(5).collect();
+
+        assert_eq!(encoded[0], [0, 0, 17, 6, 0]);
+        assert_eq!(encoded[1], [1, 0, 10, 0, 0]);
+        assert_eq!(encoded[2], [0, 11, 2, 1, 0]);
+        assert_eq!(encoded[4], [1, 2, 3, 2, 0]);
+        assert_eq!(encoded[8], [0, 1, 6, 3, 0]);
+        // `é` is one UTF-16 unit but two bytes.
+        assert_eq!(encoded[9], [0, 6, 1, 7, 0]);
+        assert_eq!(encoded[10], [0, 2, 11, 5, 0]);
+        assert_eq!(encoded[11], [1, 0, 1, 7, 0]);
+    }
+}